@@ -0,0 +1,46 @@
+/// Benchmark: the batched Keccak permutation vs. the scalar one.
+///
+/// [`f1600x4`] and [`f1600x8`] permute their states together with AVX2 on x86-64 CPUs supporting it,
+/// and fall back to [`keccak::f1600`] otherwise.
+/// This compares them against permuting the same states one at a time with [`keccak::f1600`].
+///
+/// Run with `cargo bench --bench keccak`.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use nimue::hash::keccak::{f1600x4, f1600x8};
+
+const ROUNDS: u32 = 100_000;
+
+/// Average time to permute `L` states with `batched` and one at a time with [`keccak::f1600`].
+fn bench<const L: usize>(batched: fn(&mut [[u64; 25]; L])) -> (Duration, Duration) {
+    let mut states: [[u64; 25]; L] =
+        core::array::from_fn(|l| core::array::from_fn(|i| (25 * l + i) as u64));
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(&mut states).iter_mut().for_each(keccak::f1600);
+    }
+    let scalar = start.elapsed() / ROUNDS;
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        batched(black_box(&mut states));
+    }
+    let batched = start.elapsed() / ROUNDS;
+    (scalar, batched)
+}
+
+fn report(name: &str, (scalar, batched): (Duration, Duration)) {
+    println!("{name}: one at a time {scalar:?}, batched {batched:?}");
+}
+
+fn main() {
+    #[cfg(target_arch = "x86_64")]
+    let avx2 = std::is_x86_feature_detected!("avx2");
+    #[cfg(not(target_arch = "x86_64"))]
+    let avx2 = false;
+    println!("AVX2: {avx2}");
+    report("4 states", bench(f1600x4));
+    report("8 states", bench(f1600x8));
+}
@@ -0,0 +1,124 @@
+"""
+Generate the round constants and the (alpha, beta) pairs for the Griffin permutation
+(https://eprint.iacr.org/2022/403).
+
+Field elements are sampled by rejection from a SHAKE128 stream seeded with the parameters of the instance.
+The pair (alpha, beta) is sampled so that alpha^2 - 4 beta is a quadratic non-residue.
+The output is a Rust module to be placed under `src/plugins/ark/griffin/`:
+
+$ python3 scripts/griffin_constants.py > src/plugins/ark/griffin/bls12_381/fr_3_2.rs
+
+With `--test-vector`, print instead the permutation of [1, ..., t], for t = 4 and t = 8,
+on a toy instance with 3 rounds, round constants c[r][i] = r t + i + 1, and (alpha, beta) = (2, 3).
+"""
+import hashlib
+import sys
+
+
+class FieldStream:
+    def __init__(self, p, seed):
+        self.p = p
+        self.bytes_per_int = (p.bit_length() + 7) // 8
+        self.xof = hashlib.shake_128(seed.encode("ascii"))
+        self.offset = 0
+
+    def next(self):
+        while True:
+            end = self.offset + self.bytes_per_int
+            chunk = self.xof.digest(end)[self.offset:end]
+            self.offset = end
+            x = int.from_bytes(chunk, "little") % 2 ** self.p.bit_length()
+            if x < self.p:
+                return x
+
+
+def is_square(x, p):
+    return pow(x, (p - 1) // 2, p) == 1
+
+
+def griffin_constants(p, t, d, rounds):
+    stream = FieldStream(p, "Griffin(%i,%i,%i,%i)" % (p, t, d, rounds))
+    constants = [[stream.next() for _ in range(t)] for _ in range(rounds - 1)]
+    while True:
+        alpha, beta = stream.next(), stream.next()
+        if not is_square((alpha * alpha - 4 * beta) % p, p):
+            return constants, alpha, beta
+
+
+def m4(x, p):
+    matrix = [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+    return [sum(a * b for a, b in zip(row, x)) % p for row in matrix]
+
+
+def linear_layer(state, p):
+    t = len(state)
+    if t == 3:
+        return [(x + sum(state)) % p for x in state]
+    chunks = [m4(state[i:i + 4], p) for i in range(0, t, 4)]
+    if t == 4:
+        return chunks[0]
+    sums = [sum(column) for column in zip(*chunks)]
+    return [(x + s) % p for chunk in chunks for x, s in zip(chunk, sums)]
+
+
+def non_linear_layer(x, p, d, alpha, beta):
+    y = [pow(x[0], pow(d, -1, p - 1), p), pow(x[1], d, p)]
+    for i in range(2, len(x)):
+        # L_i(y_0, y_1, x_{i-1}) = (i-1) y_0 + y_1 + x_{i-1}, with no x term for i = 2.
+        l = ((i - 1) * y[0] + y[1] + (x[i - 1] if i > 2 else 0)) % p
+        y.append(x[i] * (l * l + (i - 1) * alpha * l + (i - 1) ** 2 * beta) % p)
+    return y
+
+
+def griffin_permutation(state, p, d, alpha, beta, constants):
+    state = linear_layer(state, p)
+    for r in range(len(constants) + 1):
+        state = linear_layer(non_linear_layer(state, p, d, alpha, beta), p)
+        if r < len(constants):
+            state = [(x + c) % p for x, c in zip(state, constants[r])]
+    return state
+
+
+def limbs(x):
+    out = []
+    while x:
+        out.append(x % 2 ** 64)
+        x //= 2 ** 64
+    return out
+
+
+def rust_matrix(name, field, rows):
+    out = "pub const %s: &[[%s; %d]] = &[\n" % (name, field, len(rows[0]))
+    for row in rows:
+        out += "    [\n"
+        out += "".join('        MontFp!("%d"),\n' % x for x in row)
+        out += "    ],\n"
+    return out + "];\n"
+
+
+if __name__ == '__main__':
+    # BLS12-381 scalar field
+    p = 0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+    t, capacity, d, rounds = 3, 1, 5, 12
+
+    if "--test-vector" in sys.argv:
+        for t in [4, 8]:
+            constants = [[r * t + i + 1 for i in range(t)] for r in range(2)]
+            print(t, griffin_permutation(list(range(1, t + 1)), p, d, 2, 3, constants))
+        sys.exit(0)
+
+    constants, alpha, beta = griffin_constants(p, t, d, rounds)
+    d_inv = pow(d, -1, p - 1)
+
+    print("//! Generated with `scripts/griffin_constants.py`.")
+    print("use ark_bls12_381::Fr;")
+    print("use ark_ff::MontFp;\n")
+    print("pub type Field = Fr;")
+    print("pub const D: u64 = %d;" % d)
+    print("pub const D_INV: &[u64] = &[%s];" % ", ".join("%d" % x for x in limbs(d_inv)))
+    print("pub const ROUNDS: usize = %d;" % rounds)
+    print("pub const N: usize = %d;" % t)
+    print("pub const R: usize = %d;\n" % (t - capacity))
+    print('pub const ALPHA: Fr = MontFp!("%d");' % alpha)
+    print('pub const BETA: Fr = MontFp!("%d");\n' % beta)
+    print(rust_matrix("ROUND_CONSTANTS", "Fr", constants), end="")
@@ -0,0 +1,90 @@
+"""
+Generate the round constants for the Monolith permutation (https://eprint.iacr.org/2023/1025),
+and check that the S-boxes and the circulant matrices used in `src/plugins/small_fields/monolith/`
+are bijective.
+
+Field elements are sampled by rejection from a SHAKE128 stream seeded with the parameters of the instance.
+The output is a Rust module to be placed under `src/plugins/small_fields/monolith/`:
+
+$ python3 scripts/monolith_constants.py goldilocks > src/plugins/small_fields/monolith/goldilocks_12.rs
+$ python3 scripts/monolith_constants.py mersenne31 > src/plugins/small_fields/monolith/mersenne31_16.rs
+"""
+import hashlib
+import sys
+
+from sympy import Matrix
+
+# name: (field type, p, t, capacity, rounds, first row of the circulant matrix)
+INSTANCES = {
+    "goldilocks": (
+        "Goldilocks", 2 ** 64 - 2 ** 32 + 1, 12, 4, 6,
+        [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8],
+    ),
+    "mersenne31": (
+        "Mersenne31", 2 ** 31 - 1, 16, 8, 6,
+        [61402, 17845, 26798, 59689, 12021, 40901, 41351, 27521,
+         56951, 12034, 53865, 43244, 7454, 33823, 28750, 1108],
+    ),
+}
+
+
+class FieldStream:
+    def __init__(self, p, seed):
+        self.p = p
+        self.bytes_per_int = (p.bit_length() + 7) // 8
+        self.xof = hashlib.shake_128(seed.encode("ascii"))
+        self.offset = 0
+
+    def next(self):
+        while True:
+            end = self.offset + self.bytes_per_int
+            chunk = self.xof.digest(end)[self.offset:end]
+            self.offset = end
+            x = int.from_bytes(chunk, "little") % 2 ** self.p.bit_length()
+            if x < self.p:
+                return x
+
+
+def rotl(x, r, bits):
+    mask = 2 ** bits - 1
+    return ((x << r) | (x >> (bits - r))) & mask
+
+
+def s_box(y, bits):
+    mask = 2 ** bits - 1
+    chi = ~rotl(y, 1, bits) & rotl(y, 2, bits) & mask
+    if bits == 8:
+        chi &= rotl(y, 3, bits)
+    return rotl(y ^ chi, 1, bits)
+
+
+def check_s_boxes():
+    for bits in (7, 8):
+        assert sorted(s_box(y, bits) for y in range(2 ** bits)) == list(range(2 ** bits))
+        assert s_box(2 ** bits - 1, bits) == 2 ** bits - 1
+
+
+def check_circulant(row, p):
+    t = len(row)
+    assert Matrix(t, t, lambda i, j: row[(j - i) % t]).det() % p != 0
+
+
+if __name__ == '__main__':
+    field, p, t, capacity, rounds, row = INSTANCES[sys.argv[1]]
+    check_s_boxes()
+    check_circulant(row, p)
+
+    stream = FieldStream(p, "Monolith(%i,%i,%i)" % (p, t, rounds))
+    constants = [[stream.next() for _ in range(t)] for _ in range(rounds - 1)]
+
+    print("//! Generated with `scripts/monolith_constants.py`.")
+    print("use crate::plugins::small_fields::%s;\n" % field)
+    print("pub type Field = %s;" % field)
+    print("pub const ROUNDS: usize = %d;" % rounds)
+    print("pub const N: usize = %d;" % t)
+    print("pub const R: usize = %d;\n" % (t - capacity))
+    print("pub const MDS_ROW: [u64; %d] = [%s];\n" % (t, ", ".join("%d" % x for x in row)))
+    print("pub const ROUND_CONSTANTS: &[[%s; %d]] = &[" % (field, t))
+    for row in constants:
+        print("    [%s]," % ", ".join("%s(%d)" % (field, x) for x in row))
+    print("];")
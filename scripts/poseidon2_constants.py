@@ -0,0 +1,106 @@
+"""
+Generate the round constants and the internal matrix for the Poseidon2 permutation
+(https://eprint.iacr.org/2023/323) over small prime fields.
+
+Round constants are sampled with the Grain LFSR of the Poseidon reference implementation,
+initialized with the parameters of the instance. For partial rounds, only the first constant
+of each round is kept.
+The internal matrix is $1 1^T + diag(d)$, where the diagonal `d` is sampled from the same stream,
+and is accepted only if the minimal polynomials of its first 2t powers are irreducible of degree t
+(i.e., the matrix admits no invariant subspace trail).
+
+The output is a Rust module to be placed under `src/plugins/small_fields/poseidon2/`:
+
+$ python3 scripts/poseidon2_constants.py goldilocks > src/plugins/small_fields/poseidon2/goldilocks_12.rs
+$ python3 scripts/poseidon2_constants.py babybear > src/plugins/small_fields/poseidon2/babybear_16.rs
+$ python3 scripts/poseidon2_constants.py mersenne31 > src/plugins/small_fields/poseidon2/mersenne31_16.rs
+"""
+import sys
+
+from sympy import Matrix, Poly, symbols
+
+# name: (field type, p, t, capacity, alpha, full rounds, partial rounds)
+INSTANCES = {
+    "goldilocks": ("Goldilocks", 2 ** 64 - 2 ** 32 + 1, 12, 4, 7, 8, 22),
+    "babybear": ("BabyBear", 2 ** 31 - 2 ** 27 + 1, 16, 8, 7, 8, 13),
+    "mersenne31": ("Mersenne31", 2 ** 31 - 1, 16, 8, 5, 8, 14),
+}
+
+
+def grain_stream(p, t, full_rounds, partial_rounds):
+    n = p.bit_length()
+    bits = [0, 1]  # prime field
+    bits += [0, 0, 0, 0]  # x^alpha S-box
+    for value, length in ((n, 12), (t, 12), (full_rounds, 10), (partial_rounds, 10)):
+        bits += [int(b) for b in bin(value)[2:].zfill(length)]
+    bits += [1] * 30
+
+    def step():
+        new_bit = bits[62] ^ bits[51] ^ bits[38] ^ bits[23] ^ bits[13] ^ bits[0]
+        bits.pop(0)
+        bits.append(new_bit)
+        return new_bit
+
+    for _ in range(160):
+        step()
+
+    def next_bit():
+        while True:
+            if step() == 1:
+                return step()
+            step()
+
+    while True:
+        x = 0
+        for _ in range(n):
+            x = (x << 1) | next_bit()
+        if x < p:
+            yield x
+
+
+def has_no_invariant_subspace(matrix, p, t):
+    x = symbols("x")
+    power = matrix
+    for _ in range(2 * t):
+        charpoly = Poly(power.charpoly(x).all_coeffs(), x, modulus=p)
+        if not charpoly.is_irreducible:
+            return False
+        power = (power * matrix).applyfunc(lambda e: e % p)
+    return True
+
+
+def internal_diagonal(stream, p, t):
+    while True:
+        diagonal = [next(stream) for _ in range(t)]
+        matrix = Matrix(t, t, lambda i, j: (1 + (diagonal[i] if i == j else 0)) % p)
+        if has_no_invariant_subspace(matrix, p, t):
+            return diagonal
+
+
+def rust_array(field, values):
+    return "[%s]" % ", ".join("%s(%d)" % (field, x) for x in values)
+
+
+if __name__ == '__main__':
+    field, p, t, capacity, alpha, full_rounds, partial_rounds = INSTANCES[sys.argv[1]]
+    stream = grain_stream(p, t, full_rounds, partial_rounds)
+    constants = [[next(stream) for _ in range(t)] for _ in range(full_rounds + partial_rounds)]
+    half = full_rounds // 2
+    external = constants[:half] + constants[half + partial_rounds:]
+    internal = [row[0] for row in constants[half:half + partial_rounds]]
+    diagonal = internal_diagonal(stream, p, t)
+
+    print("//! Generated with `scripts/poseidon2_constants.py`.")
+    print("use crate::plugins::small_fields::%s;\n" % field)
+    print("pub type Field = %s;" % field)
+    print("pub const ALPHA: u64 = %d;" % alpha)
+    print("pub const FULL_ROUNDS: usize = %d;" % full_rounds)
+    print("pub const PARTIAL_ROUNDS: usize = %d;" % partial_rounds)
+    print("pub const N: usize = %d;" % t)
+    print("pub const R: usize = %d;\n" % (t - capacity))
+    print("pub const EXTERNAL_CONSTANTS: &[[%s; %d]] = &[" % (field, t))
+    for row in external:
+        print("    %s," % rust_array(field, row))
+    print("];\n")
+    print("pub const INTERNAL_CONSTANTS: &[%s] = &%s;\n" % (field, rust_array(field, internal)))
+    print("pub const INTERNAL_DIAGONAL: [%s; %d] = %s;" % (field, t, rust_array(field, diagonal)))
@@ -0,0 +1,82 @@
+"""
+Generate the MDS matrix and the round constants of Winterfell's `Rp64_256`,
+an instance of Rescue-Prime (https://eprint.iacr.org/2020/1143) over the field $p = 2^{64} - 2^{32} + 1$.
+
+Winterfell uses a circulant MDS matrix with small entries,
+and round constants derived with SHAKE256 as in the Rescue-Prime reference implementation,
+seeded with the string "RPO(p,m,c,λ)" of Rescue-Prime Optimized (https://eprint.iacr.org/2022/1577).
+
+The output is a Rust module to be placed under `src/plugins/ark/rescue/`:
+
+$ python3 scripts/rescue_prime_constants.py > src/plugins/ark/rescue/f64/rp64_256.rs
+
+With `--test-vector`, print instead the permutation of the state [0, 1, ..., 11].
+"""
+import hashlib
+import sys
+
+# First row of Winterfell's circulant MDS matrix.
+MDS_ROW = [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8]
+
+
+def mds_matrix(p, m):
+    return [[MDS_ROW[(j - i) % m] % p for j in range(m)] for i in range(m)]
+
+
+def round_constants(p, m, capacity, security_level, rounds):
+    bytes_per_int = (p.bit_length() + 7) // 8 + 1
+    seed = "RPO(%i,%i,%i,%i)" % (p, m, capacity, security_level)
+    stream = hashlib.shake_256(seed.encode("ascii")).digest(bytes_per_int * 2 * m * rounds)
+    return [
+        int.from_bytes(stream[bytes_per_int * i:bytes_per_int * (i + 1)], "little") % p
+        for i in range(2 * m * rounds)
+    ]
+
+
+def permutation(p, alpha, mds, ark1, ark2, state):
+    alpha_inv = pow(alpha, -1, p - 1)
+    m = len(state)
+    apply_mds = lambda s: [sum(mds[i][j] * s[j] for j in range(m)) % p for i in range(m)]
+    for c1, c2 in zip(ark1, ark2):
+        state = apply_mds([pow(x, alpha, p) for x in state])
+        state = [(x + c) % p for x, c in zip(state, c1)]
+        state = apply_mds([pow(x, alpha_inv, p) for x in state])
+        state = [(x + c) % p for x, c in zip(state, c2)]
+    return state
+
+
+def rust_matrix(name, field, rows):
+    out = "pub const %s: &[[%s; %d]] = &[\n" % (name, field, len(rows[0]))
+    for row in rows:
+        out += "    [\n"
+        out += "".join('        MontFp!("%d"),\n' % x for x in row)
+        out += "    ],\n"
+    return out + "];\n"
+
+
+if __name__ == '__main__':
+    p = 2 ** 64 - 2 ** 32 + 1
+    m, capacity, rounds, alpha, security_level = 12, 4, 7, 7, 128
+
+    mds = mds_matrix(p, m)
+    constants = round_constants(p, m, capacity, security_level, rounds)
+    ark1 = [constants[2 * m * r:2 * m * r + m] for r in range(rounds)]
+    ark2 = [constants[2 * m * r + m:2 * m * (r + 1)] for r in range(rounds)]
+    alpha_inv = pow(alpha, -1, p - 1)
+
+    if "--test-vector" in sys.argv:
+        print(permutation(p, alpha, mds, ark1, ark2, list(range(m))))
+        sys.exit(0)
+
+    print("//! Generated with `scripts/rescue_prime_constants.py`.")
+    print("use ark_ff::MontFp;\n")
+    print("use super::F64;\n")
+    print("pub type Field = F64;")
+    print("pub const ALPHA: u64 = %d;" % alpha)
+    print("pub const ALPHA_INV: &[u64] = &[%d];" % alpha_inv)
+    print("pub const ROUNDS: usize = %d;" % rounds)
+    print("pub const N: usize = %d;" % m)
+    print("pub const R: usize = %d;\n" % (m - capacity))
+    print(rust_matrix("MDS", "F64", mds))
+    print(rust_matrix("ARK1", "F64", ark1))
+    print(rust_matrix("ARK2", "F64", ark2), end="")
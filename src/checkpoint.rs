@@ -0,0 +1,130 @@
+use zeroize::{Zeroize, Zeroizing};
+
+use crate::errors::IOPatternError;
+
+/// Version of the serialization format of exported states.
+/// Bump it whenever the layout of any exported state changes.
+pub(crate) const STATE_VERSION: u8 = 1;
+
+/// The kind of object whose state is exported,
+/// so that states of different objects cannot be mistaken for one another.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub(crate) enum StateKind {
+    DuplexSponge = 1,
+    DigestBridge = 2,
+    Safe = 3,
+    Merlin = 4,
+}
+
+/// Append `bytes` to `buf`.
+///
+/// If `buf` has to grow, its contents are moved to a new allocation and the previous one is zeroized,
+/// so that no copy of secret data is left behind.
+pub(crate) fn extend_zeroizing(buf: &mut Vec<u8>, bytes: &[u8]) {
+    if buf.capacity() - buf.len() < bytes.len() {
+        let capacity = usize::max(2 * buf.capacity(), buf.len() + bytes.len());
+        let mut grown = Vec::with_capacity(capacity);
+        grown.extend_from_slice(buf);
+        buf.zeroize();
+        *buf = grown;
+    }
+    buf.extend_from_slice(bytes);
+}
+
+/// Serializes a state as `version || kind || fields`.
+/// The buffer is zeroized on drop.
+pub(crate) struct StateWriter(Zeroizing<Vec<u8>>);
+
+impl StateWriter {
+    /// A writer for a state of `len` bytes of fields, allocated upfront.
+    /// Writing more is possible, cf. [`extend_zeroizing`].
+    pub(crate) fn new(kind: StateKind, len: usize) -> Self {
+        let mut buf = Vec::with_capacity(2 + len);
+        buf.extend_from_slice(&[STATE_VERSION, kind as u8]);
+        Self(Zeroizing::new(buf))
+    }
+
+    pub(crate) fn write_u64(&mut self, value: u64) -> &mut Self {
+        self.write_bytes(&value.to_le_bytes())
+    }
+
+    pub(crate) fn write_bytes(&mut self, bytes: &[u8]) -> &mut Self {
+        extend_zeroizing(&mut self.0, bytes);
+        self
+    }
+
+    /// Write the length of `bytes` followed by `bytes`.
+    pub(crate) fn write_prefixed(&mut self, bytes: &[u8]) -> &mut Self {
+        self.write_u64(bytes.len() as u64).write_bytes(bytes)
+    }
+
+    pub(crate) fn finish(self) -> Zeroizing<Vec<u8>> {
+        self.0
+    }
+}
+
+impl std::io::Write for StateWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_bytes(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Deserializes a state written by [`StateWriter`].
+pub(crate) struct StateReader<'a>(&'a [u8]);
+
+impl<'a> StateReader<'a> {
+    pub(crate) fn new(state: &'a [u8], kind: StateKind) -> Result<Self, IOPatternError> {
+        match state {
+            [STATE_VERSION, k, rest @ ..] if *k == kind as u8 => Ok(Self(rest)),
+            [STATE_VERSION, ..] => {
+                Err(format!("Invalid state: expected a {:?} state", kind).into())
+            }
+            [v, ..] => Err(format!("Unsupported state version {}", v).into()),
+            [] => Err("Invalid state: empty".into()),
+        }
+    }
+
+    pub(crate) fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], IOPatternError> {
+        if self.0.len() < len {
+            return Err("Invalid state: unexpected end of input".into());
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    pub(crate) fn read_u64(&mut self) -> Result<u64, IOPatternError> {
+        let bytes = self.read_bytes(8)?;
+        Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+    }
+
+    pub(crate) fn read_usize(&mut self) -> Result<usize, IOPatternError> {
+        usize::try_from(self.read_u64()?).map_err(|_| "Invalid state: length overflow".into())
+    }
+
+    /// Read a length followed by as many bytes.
+    pub(crate) fn read_prefixed(&mut self) -> Result<&'a [u8], IOPatternError> {
+        let len = self.read_usize()?;
+        self.read_bytes(len)
+    }
+
+    /// Read all the remaining bytes.
+    pub(crate) fn read_rest(&mut self) -> &'a [u8] {
+        core::mem::take(&mut self.0)
+    }
+
+    /// Make sure the whole state has been consumed.
+    pub(crate) fn finish(self) -> Result<(), IOPatternError> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err("Invalid state: trailing bytes".into())
+        }
+    }
+}
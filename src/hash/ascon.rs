@@ -0,0 +1,127 @@
+//! **Warning**: this function is not Ascon-Hash.
+//! Despite internally we use the same permutation function,
+//! we build a duplex sponge in overwrite mode
+//! on the top of it using the `DuplexSponge` trait.
+use super::sponge::{DuplexSponge, Sponge};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A duplex sponge based on the Ascon permutation $p^{12}$ over a 320-bit state,
+/// with a rate of 64 bits and a capacity of 256 bits, using [`DuplexSponge`].
+pub type Ascon = DuplexSponge<AsconState>;
+
+/// Round constants of the 12-round Ascon permutation.
+const ROUND_CONSTANTS: [u64; 12] = [
+    0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b,
+];
+
+/// The Ascon permutation $p^{12}$, over five 64-bit words.
+fn ascon_p12(x: &mut [u64; 5]) {
+    for c in ROUND_CONSTANTS {
+        // constant addition
+        x[2] ^= c;
+        // substitution layer
+        x[0] ^= x[4];
+        x[4] ^= x[3];
+        x[2] ^= x[1];
+        let t = [
+            !x[0] & x[1],
+            !x[1] & x[2],
+            !x[2] & x[3],
+            !x[3] & x[4],
+            !x[4] & x[0],
+        ];
+        for i in 0..5 {
+            x[i] ^= t[(i + 1) % 5];
+        }
+        x[1] ^= x[0];
+        x[0] ^= x[4];
+        x[3] ^= x[2];
+        x[2] = !x[2];
+        // linear diffusion layer
+        x[0] ^= x[0].rotate_right(19) ^ x[0].rotate_right(28);
+        x[1] ^= x[1].rotate_right(61) ^ x[1].rotate_right(39);
+        x[2] ^= x[2].rotate_right(1) ^ x[2].rotate_right(6);
+        x[3] ^= x[3].rotate_right(10) ^ x[3].rotate_right(17);
+        x[4] ^= x[4].rotate_right(7) ^ x[4].rotate_right(41);
+    }
+}
+
+/// The 40-byte Ascon state.
+/// Words are encoded in little-endian order, as in NIST SP 800-232.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct AsconState([u8; 40]);
+
+impl Sponge for AsconState {
+    type U = u8;
+    const N: usize = 40;
+    const R: usize = 8;
+    const SECURITY_LEVEL: usize = (Self::N - Self::R) * 4;
+
+    fn new(tag: [u8; 32]) -> Self {
+        let mut state = Self::default();
+        state.0[Self::R..Self::R + 32].copy_from_slice(&tag);
+        state
+    }
+
+    fn permute(&mut self) {
+        let mut words = [0u64; 5];
+        for (word, bytes) in words.iter_mut().zip(self.0.chunks_exact(8)) {
+            *word = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        ascon_p12(&mut words);
+        for (word, bytes) in words.iter().zip(self.0.chunks_exact_mut(8)) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+        words.zeroize();
+    }
+}
+
+impl Default for AsconState {
+    fn default() -> Self {
+        Self([0u8; Self::N])
+    }
+}
+
+impl AsRef<[u8]> for AsconState {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for AsconState {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+#[test]
+fn test_ascon_kat() {
+    // The initial state of Ascon-Hash, from the Ascon v1.2 specification.
+    let mut state = [0x00400c0000000100, 0, 0, 0, 0];
+    ascon_p12(&mut state);
+    assert_eq!(
+        state,
+        [
+            0xee9398aadb67f03d,
+            0x8bb21831c60f1002,
+            0xb48a92db98d5da62,
+            0x43189921b8f8e3e8,
+            0x348fa5c9d525e140
+        ]
+    );
+}
+
+#[test]
+fn test_ascon_duplex_kat() {
+    use crate::DuplexHash;
+
+    let iv = core::array::from_fn(|i| i as u8);
+    let mut output = [0u8; 32];
+    Ascon::new(iv)
+        .absorb_unchecked(b"nimue")
+        .squeeze_unchecked(&mut output);
+    assert_eq!(
+        hex::encode(output),
+        "cc3285e8267a5b187a03270ebebda4d91672773a8e38d2fdd3dfd9a83e5ab170"
+    );
+}
@@ -9,25 +9,36 @@ use zeroize::{Zeroize, ZeroizeOnDrop};
 /// using [`DuplexSponge`].
 pub type Keccak = DuplexSponge<AlignedKeccakState>;
 
-fn transmute_state(st: &mut AlignedKeccakState) -> &mut [u64; 25] {
-    unsafe { &mut *(st as *mut AlignedKeccakState as *mut [u64; 25]) }
+/// A duplex sponge based on the permutation [`keccak::f1600`]
+/// with capacity 1024 bits (rate 72 bytes), as in SHA3-512.
+/// This provides 256 bits of security also against quantum adversaries.
+pub type Keccak512 = DuplexSponge<AlignedKeccakState<72>>;
+
+fn transmute_state<const R: usize>(st: &mut AlignedKeccakState<R>) -> &mut [u64; 25] {
+    unsafe { &mut *(st as *mut AlignedKeccakState<R> as *mut [u64; 25]) }
 }
 
 /// This is a wrapper around 200-byte buffer that's always 8-byte aligned
 /// to make pointers to it safely convertible to pointers to [u64; 25]
-/// (since u64 words must be 8-byte aligned)
+/// (since u64 words must be 8-byte aligned).
+/// The rate `R` defaults to 136 bytes (capacity 512 bits), as in SHA3-256.
 #[derive(Clone, Zeroize, ZeroizeOnDrop)]
 #[repr(align(8))]
-pub struct AlignedKeccakState([u8; 200]);
+pub struct AlignedKeccakState<const R: usize = 136>([u8; 200]);
 
-impl Sponge for AlignedKeccakState {
+impl<const R: usize> Sponge for AlignedKeccakState<R> {
     type U = u8;
-    const N: usize = 136 + 64;
-    const R: usize = 136;
+    const N: usize = 200;
+    const R: usize = R;
+    const SECURITY_LEVEL: usize = (Self::N - R) * 4;
 
     fn new(tag: [u8; 32]) -> Self {
+        assert!(
+            R + 32 <= Self::N,
+            "The capacity must be large enough to hold the IV."
+        );
         let mut state = Self::default();
-        state.0[Self::R..Self::R + 32].copy_from_slice(&tag);
+        state.0[R..R + 32].copy_from_slice(&tag);
         state
     }
 
@@ -36,20 +47,221 @@ impl Sponge for AlignedKeccakState {
     }
 }
 
-impl Default for AlignedKeccakState {
+impl<const R: usize> Default for AlignedKeccakState<R> {
     fn default() -> Self {
-        Self([0u8; Self::N])
+        Self([0u8; 200])
     }
 }
 
-impl AsRef<[u8]> for AlignedKeccakState {
+impl<const R: usize> AsRef<[u8]> for AlignedKeccakState<R> {
     fn as_ref(&self) -> &[u8] {
         &self.0
     }
 }
 
-impl AsMut<[u8]> for AlignedKeccakState {
+impl<const R: usize> AsMut<[u8]> for AlignedKeccakState<R> {
     fn as_mut(&mut self) -> &mut [u8] {
         &mut self.0
     }
 }
+
+/// Number of states permuted at once by [`f1600_batch`].
+pub const BATCH_SIZE: usize = 8;
+
+/// Round constants of Keccak-f\[1600\].
+const RC: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808a,
+    0x8000000080008000,
+    0x000000000000808b,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008a,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000a,
+    0x000000008000808b,
+    0x800000000000008b,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800a,
+    0x800000008000000a,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+/// Rotation offsets of the ρ step, in the order in which π visits the lanes.
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// The lanes visited by the π step, starting from lane 1.
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Keccak-f\[1600\] over 4 states with AVX2, one state per 64-bit lane of a 256-bit vector.
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    use super::{PI, RC, RHO};
+
+    /// Rotate every lane of `x` left by `n` bits, with `0 < n < 64`.
+    /// AVX2 has no 64-bit rotation: combine two shifts.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn rotate_left(x: __m256i, n: u32) -> __m256i {
+        _mm256_or_si256(
+            _mm256_sll_epi64(x, _mm_cvtsi32_si128(n as i32)),
+            _mm256_srl_epi64(x, _mm_cvtsi32_si128(64 - n as i32)),
+        )
+    }
+
+    /// The permutation, where `a[i]` holds word `i` of each of the 4 states.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn keccak_f(a: &mut [__m256i; 25]) {
+        for rc in RC {
+            // θ
+            let mut c = [_mm256_setzero_si256(); 5];
+            for (x, c) in c.iter_mut().enumerate() {
+                *c = _mm256_xor_si256(
+                    _mm256_xor_si256(a[x], a[x + 5]),
+                    _mm256_xor_si256(_mm256_xor_si256(a[x + 10], a[x + 15]), a[x + 20]),
+                );
+            }
+            for x in 0..5 {
+                let d = _mm256_xor_si256(c[(x + 4) % 5], rotate_left(c[(x + 1) % 5], 1));
+                for y in 0..5 {
+                    a[x + 5 * y] = _mm256_xor_si256(a[x + 5 * y], d);
+                }
+            }
+            // ρ and π
+            let mut last = a[1];
+            for (&pi, &rho) in PI.iter().zip(&RHO) {
+                let next = a[pi];
+                a[pi] = rotate_left(last, rho);
+                last = next;
+            }
+            // χ
+            for y in 0..5 {
+                let row = [
+                    a[5 * y],
+                    a[5 * y + 1],
+                    a[5 * y + 2],
+                    a[5 * y + 3],
+                    a[5 * y + 4],
+                ];
+                for x in 0..5 {
+                    a[x + 5 * y] = _mm256_xor_si256(
+                        row[x],
+                        _mm256_andnot_si256(row[(x + 1) % 5], row[(x + 2) % 5]),
+                    );
+                }
+            }
+            // ι
+            a[0] = _mm256_xor_si256(a[0], _mm256_set1_epi64x(rc as i64));
+        }
+    }
+
+    /// Load the states in the lanes of the vectors, permute them, and store them back.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support AVX2.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn f1600x4(states: &mut [[u64; 25]; 4]) {
+        let mut a = [_mm256_setzero_si256(); 25];
+        for (i, a) in a.iter_mut().enumerate() {
+            *a = _mm256_set_epi64x(
+                states[3][i] as i64,
+                states[2][i] as i64,
+                states[1][i] as i64,
+                states[0][i] as i64,
+            );
+        }
+        keccak_f(&mut a);
+        for (i, a) in a.iter().enumerate() {
+            let mut words = [0u64; 4];
+            _mm256_storeu_si256(words.as_mut_ptr() as *mut __m256i, *a);
+            for (state, word) in states.iter_mut().zip(words) {
+                state[i] = word;
+            }
+        }
+    }
+}
+
+/// Apply the permutation [`keccak::f1600`] to 4 independent states at once.
+///
+/// On x86-64 CPUs supporting AVX2, the states are permuted together, one per lane of 256-bit vectors;
+/// otherwise, they are permuted one at a time with [`keccak::f1600`].
+pub fn f1600x4(states: &mut [[u64; 25]; 4]) {
+    #[cfg(target_arch = "x86_64")]
+    if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: the CPU supports AVX2.
+        return unsafe { avx2::f1600x4(states) };
+    }
+    states.iter_mut().for_each(keccak::f1600);
+}
+
+/// Apply the permutation [`keccak::f1600`] to 8 independent states at once, as two calls to [`f1600x4`].
+pub fn f1600x8(states: &mut [[u64; 25]; 8]) {
+    for chunk in states.chunks_exact_mut(4) {
+        f1600x4(chunk.try_into().unwrap());
+    }
+}
+
+/// Permute a batch of Keccak states, [`BATCH_SIZE`] at a time.
+///
+/// This is useful to process many independent sponges at once,
+/// e.g. when grinding a proof-of-work, or when verifying the proofs of work of several transcripts
+/// with [`verify_pow_batch`][`crate::plugins::pow::verify_pow_batch`].
+pub fn f1600_batch<const R: usize>(states: &mut [AlignedKeccakState<R>]) {
+    let mut chunks = states.chunks_exact_mut(BATCH_SIZE);
+    for chunk in &mut chunks {
+        let mut words: [[u64; 25]; BATCH_SIZE] =
+            core::array::from_fn(|l| *transmute_state(&mut chunk[l]));
+        f1600x8(&mut words);
+        for (state, words) in chunk.iter_mut().zip(&words) {
+            *transmute_state(state) = *words;
+        }
+    }
+    chunks
+        .into_remainder()
+        .iter_mut()
+        .for_each(AlignedKeccakState::permute);
+}
+
+#[test]
+fn test_f1600_batch() {
+    use rand::RngCore;
+
+    let mut rng = rand::thread_rng();
+    let mut states: [[u64; 25]; 8] =
+        core::array::from_fn(|_| core::array::from_fn(|_| rng.next_u64()));
+    let mut expected = states;
+    expected.iter_mut().for_each(keccak::f1600);
+
+    let mut x4: [[u64; 25]; 4] = core::array::from_fn(|l| states[l]);
+    f1600x4(&mut x4);
+    assert_eq!(x4, expected[..4]);
+    f1600x8(&mut states);
+    assert_eq!(states, expected);
+
+    let mut batch = (0..11u8)
+        .map(|i| AlignedKeccakState::new([i; 32]))
+        .collect::<Vec<AlignedKeccakState>>();
+    let mut expected = batch.clone();
+    f1600_batch(&mut batch);
+    expected.iter_mut().for_each(AlignedKeccakState::permute);
+    for (got, expected) in batch.iter().zip(&expected) {
+        assert_eq!(got.as_ref(), expected.as_ref());
+    }
+}
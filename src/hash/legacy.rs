@@ -18,9 +18,11 @@
 //!
 use digest::{core_api::BlockSizeUser, typenum::Unsigned, Digest, FixedOutputReset, Reset};
 use generic_array::GenericArray;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 use super::DuplexHash;
+use crate::checkpoint::{extend_zeroizing, StateKind, StateReader, StateWriter};
+use crate::errors::IOPatternError;
 
 /// A Bridge to our sponge interface for legacy `Digest` implementations.
 #[derive(Clone)]
@@ -34,6 +36,9 @@ pub struct DigestBridge<D: Digest + Clone + Reset> {
     mode: Mode,
     /// Digest bytes left over from a previous squeeze.
     leftovers: Vec<u8>,
+    /// Bytes absorbed since the chaining value was last updated,
+    /// kept in order to export the state in the middle of an absorption.
+    absorbed: Vec<u8>,
 }
 
 #[derive(Clone, PartialEq, Eq)]
@@ -93,6 +98,8 @@ impl<D: BlockSizeUser + Digest + Clone + Reset> DigestBridge<D> {
 impl<D: Clone + Digest + Reset> Zeroize for DigestBridge<D> {
     fn zeroize(&mut self) {
         self.cv.zeroize();
+        self.leftovers.zeroize();
+        self.absorbed.zeroize();
         Digest::reset(&mut self.hasher);
     }
 }
@@ -110,11 +117,15 @@ impl<D: BlockSizeUser + Digest + Clone + FixedOutputReset> Default for DigestBri
             cv: GenericArray::default(),
             mode: Mode::Start,
             leftovers: Vec::new(),
+            absorbed: Vec::new(),
         }
     }
 }
 
 impl<D: BlockSizeUser + Digest + Clone + FixedOutputReset> DuplexHash<u8> for DigestBridge<D> {
+    /// Half the digest size, as for collision resistance.
+    const SECURITY_LEVEL: usize = D::OutputSize::USIZE * 4;
+
     fn new(tag: [u8; 32]) -> Self {
         // debug_assert!(size_of::<D::OutputSize>() >= 32);
         let mut bridge = Self::default();
@@ -132,6 +143,7 @@ impl<D: BlockSizeUser + Digest + Clone + FixedOutputReset> DuplexHash<u8> for Di
         }
 
         Digest::update(&mut self.hasher, input);
+        extend_zeroizing(&mut self.absorbed, input);
         self
     }
 
@@ -141,7 +153,7 @@ impl<D: BlockSizeUser + Digest + Clone + FixedOutputReset> DuplexHash<u8> for Di
         self.cv = <D as Digest>::digest(&self.hasher.finalize_reset());
         // Restart the rest of the data
         self.leftovers.zeroize();
-        self.leftovers.clear();
+        self.absorbed.zeroize();
         self.mode = Mode::Start;
         self
     }
@@ -184,6 +196,53 @@ impl<D: BlockSizeUser + Digest + Clone + FixedOutputReset> DuplexHash<u8> for Di
             unreachable!()
         }
     }
+
+    /// The state of the underlying hasher cannot be exported in general.
+    /// For this reason, the hasher is reconstructed from the chaining value,
+    /// and in the middle of an absorption, from the bytes absorbed since then.
+    fn export_state(&self) -> Result<Zeroizing<Vec<u8>>, IOPatternError> {
+        let len = 24 + Self::DIGEST_SIZE + self.leftovers.len() + self.absorbed.len();
+        let mut writer = StateWriter::new(StateKind::DigestBridge, len);
+        match self.mode {
+            Mode::Start => writer.write_u64(0),
+            Mode::Squeeze(i) => writer.write_u64(1).write_u64(i as u64),
+            Mode::Absorb => writer.write_u64(2).write_prefixed(&self.absorbed),
+        };
+        writer.write_bytes(&self.cv).write_prefixed(&self.leftovers);
+        Ok(writer.finish())
+    }
+
+    fn import_state(state: &[u8]) -> Result<Self, IOPatternError> {
+        let mut reader = StateReader::new(state, StateKind::DigestBridge)?;
+        let mut bridge = Self::default();
+        bridge.mode = match reader.read_u64()? {
+            0 => Mode::Start,
+            1 => Mode::Squeeze(reader.read_usize()?),
+            2 => {
+                bridge.absorbed = reader.read_prefixed()?.to_vec();
+                Mode::Absorb
+            }
+            _ => return Err("Invalid state: unknown mode".into()),
+        };
+        bridge
+            .cv
+            .copy_from_slice(reader.read_bytes(Self::DIGEST_SIZE)?);
+        bridge.leftovers = reader.read_prefixed()?.to_vec();
+        reader.finish()?;
+        if let Mode::Squeeze(_) = bridge.mode {
+            // Re-create the prefix hash.
+            Digest::update(&mut bridge.hasher, Self::mask_squeeze());
+            Digest::update(&mut bridge.hasher, &bridge.cv);
+        } else if bridge.mode == Mode::Absorb && bridge.leftovers.is_empty() {
+            // Re-absorb the bytes absorbed since the chaining value was last updated.
+            Digest::update(&mut bridge.hasher, Self::mask_absorb());
+            Digest::update(&mut bridge.hasher, &bridge.cv);
+            Digest::update(&mut bridge.hasher, &bridge.absorbed);
+        } else if !bridge.leftovers.is_empty() {
+            return Err("Invalid state: leftovers outside of squeeze".into());
+        }
+        Ok(bridge)
+    }
 }
 
 #[test]
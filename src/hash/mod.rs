@@ -6,17 +6,39 @@
 //! Additionally, the module exports some utilities:
 //! - [`hash::sponge::DuplexSponge`] allows to implement a [`crate::DuplexHash`] using a secure permutation function, specifying the rate `R` and the width `N`.
 //! This is done using the standard duplex sponge cosntruction in overwrite mode (cf. [Wikipedia](https://en.wikipedia.org/wiki/Sponge_function#Duplex_construction)).
+//! - [`hash::keccak::Keccak512`] is a variant of [`hash::Keccak`] with a larger capacity, for parameter sets targeting post-quantum security.
+//! - [`hash::turboshake::TurboShake`] is a faster alternative to [`hash::Keccak`], using the reduced-round permutation of TurboSHAKE.
+//! - [`hash::Ascon`] and [`hash::Xoodoo`] are sponges with a small state, suitable for constrained devices.
 //! - [`hash::legacy::DigestBridge`] takes as input any hash function implementing the NIST API via the standard [`digest::Digest`] trait and makes it suitable for usage in duplex mode for continuous absorb/squeeze.
+//! - [`hash::strobe::MerlinStrobe`] reproduces the transcripts of [`merlin`](https://merlin.cool), so that existing proofs can be verified with nimue.
+//! - [`hash::xof::XofBridge`] does the same for extendable-output functions implementing [`digest::ExtendableOutput`], such as SHAKE and Blake3.
 
+use zeroize::Zeroizing;
+
+use crate::errors::IOPatternError;
+
+/// A wrapper around the Ascon permutation.
+pub mod ascon;
 /// A wrapper around the Keccak-f\[1600\] permutation.
 pub mod keccak;
 /// Legacy hash functions support (e.g. [`sha2`](https://crates.io/crates/sha2), [`blake2`](https://crates.io/crates/blake2)).
 pub mod legacy;
 /// Sponge functions.
 pub mod sponge;
+/// A minimal STROBE implementation, compatible with [`merlin`](https://crates.io/crates/merlin) transcripts.
+pub mod strobe;
+/// A wrapper around the Keccak-p\[1600, 12\] permutation, as used in TurboSHAKE.
+pub mod turboshake;
+/// Extendable-output hash functions support (e.g. [`sha3::Shake128`](https://crates.io/crates/sha3), [`blake3`](https://crates.io/crates/blake3)).
+pub mod xof;
+/// A wrapper around the Xoodoo\[12\] permutation.
+pub mod xoodoo;
 
 // Re-export the supported hash functions.
+pub use ascon::Ascon;
 pub use keccak::Keccak;
+pub use turboshake::TurboShake;
+pub use xoodoo::Xoodoo;
 
 /// Basic units over which a sponge operates.
 ///
@@ -41,9 +63,23 @@ pub trait DuplexHash<U = u8>: Default + Clone + zeroize::Zeroize
 where
     U: Unit,
 {
+    /// The (classical) security level of the hash function, in bits.
+    ///
+    /// Protocols should not claim a security level larger than this one.
+    /// Against quantum adversaries, the generic security of a sponge is roughly halved.
+    const SECURITY_LEVEL: usize;
+
     /// Initializes a new sponge, setting up the state.
     fn new(iv: [u8; 32]) -> Self;
 
+    /// Initializes a new sponge for the given IO Pattern, whose IV is `iv`.
+    ///
+    /// By default, only the IV is used. Hash functions that need to know the structure of the protocol,
+    /// e.g. to reproduce labelled transcripts as [`strobe::MerlinStrobe`] does, can override this method.
+    fn with_io_pattern(_io_pattern: &[u8], iv: [u8; 32]) -> Self {
+        Self::new(iv)
+    }
+
     /// Absorbs new elements in the sponge.
     fn absorb_unchecked(&mut self, input: &[U]) -> &mut Self;
 
@@ -60,12 +96,19 @@ where
     /// The resulting state is compressed.
     fn ratchet_unchecked(&mut self) -> &mut Self;
 
-    // /// Exports the hash state, allowing for preprocessing.
-    // ///
-    // /// This function can be used for duplicating the state of the sponge,
-    // /// but is limited to exporting the state in a way that is compatible
-    // /// with the `load` function.
-    // fn tag(self) -> &'static [Self::U];
+    /// Exports the hash state, allowing for checkpointing.
+    ///
+    /// The output is versioned, and can be loaded back with [`DuplexHash::import_state`].
+    /// It contains secret information and is zeroized on drop.
+    /// By default, hash functions do not support exporting their state.
+    fn export_state(&self) -> Result<Zeroizing<Vec<u8>>, IOPatternError> {
+        Err("This hash function does not support exporting its state".into())
+    }
+
+    /// Loads a hash state previously exported with [`DuplexHash::export_state`].
+    fn import_state(_state: &[u8]) -> Result<Self, IOPatternError> {
+        Err("This hash function does not support importing its state".into())
+    }
 }
 
 impl Unit for u8 {
@@ -1,6 +1,8 @@
 use super::{DuplexHash, Unit};
+use crate::checkpoint::{StateKind, StateReader, StateWriter};
+use crate::errors::IOPatternError;
 
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 /// The basic state of a cryptographic sponge.
 ///
@@ -29,6 +31,12 @@ pub trait Sponge: Zeroize + Default + Clone + AsRef<[Self::U]> + AsMut<[Self::U]
     /// The rate of the sponge.
     const R: usize;
 
+    /// The security level of the sponge, in bits.
+    ///
+    /// For sponges over bytes, this is usually half the capacity, i.e. `(N - R) * 4`;
+    /// for the sponges over prime fields of this crate, half the capacity (in bits), capped at 128.
+    const SECURITY_LEVEL: usize;
+
     /// Initialize the state of the sponge using 32 bytes of seed.
     fn new(iv: [u8; 32]) -> Self;
 
@@ -36,6 +44,38 @@ pub trait Sponge: Zeroize + Default + Clone + AsRef<[Self::U]> + AsMut<[Self::U]
     fn permute(&mut self);
 }
 
+/// The security level of a sponge over a prime field of `modulus_bits` bits, with a capacity of `capacity` elements:
+/// half the capacity, capped at the 128 bits targeted by the number of rounds of the algebraic sponges of this crate.
+pub(crate) const fn algebraic_security_level(capacity: usize, modulus_bits: u32) -> usize {
+    let generic = capacity * modulus_bits as usize / 2;
+    if generic < 128 {
+        generic
+    } else {
+        128
+    }
+}
+
+/// The size in bytes of a unit of `sponge`, serialized with [`Unit::write`].
+fn unit_size<C: Sponge>(sponge: &C) -> Result<usize, IOPatternError> {
+    /// Counts the bytes written, without storing them.
+    struct Counter(usize);
+
+    impl std::io::Write for Counter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0 += buf.len();
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    C::U::write(&sponge.as_ref()[..1], &mut counter)?;
+    Ok(counter.0)
+}
+
 /// A cryptographic sponge.
 #[derive(Clone, Default, Zeroize, ZeroizeOnDrop)]
 pub struct DuplexSponge<C: Sponge> {
@@ -45,6 +85,8 @@ pub struct DuplexSponge<C: Sponge> {
 }
 
 impl<U: Unit, C: Sponge<U = U>> DuplexHash<U> for DuplexSponge<C> {
+    const SECURITY_LEVEL: usize = C::SECURITY_LEVEL;
+
     fn new(iv: [u8; 32]) -> Self {
         assert!(C::N > C::R, "Capacity of the sponge should be > 0.");
         Self {
@@ -95,10 +137,6 @@ impl<U: Unit, C: Sponge<U = U>> DuplexHash<U> for DuplexSponge<C> {
         self.squeeze_unchecked(rest)
     }
 
-    // fn tag(self) -> &'static [Self::U] {
-    //     &self.state[C::RATE..]
-    // }
-
     fn ratchet_unchecked(&mut self) -> &mut Self {
         self.sponge.permute();
         // set to zero the state up to rate
@@ -109,4 +147,51 @@ impl<U: Unit, C: Sponge<U = U>> DuplexHash<U> for DuplexSponge<C> {
         self.squeeze_pos = C::R;
         self
     }
+
+    /// The state is exported as the width [`Sponge::N`], the rate [`Sponge::R`] and the size in bytes of a unit,
+    /// so that states of different sponges are not mistaken for one another,
+    /// followed by the absorb and squeeze positions and the [`Sponge::N`] units of the permutation state,
+    /// serialized with [`Unit::write`].
+    fn export_state(&self) -> Result<Zeroizing<Vec<u8>>, IOPatternError> {
+        let unit_size = unit_size(&C::default())?;
+        let mut writer = StateWriter::new(StateKind::DuplexSponge, 40 + unit_size * C::N);
+        writer
+            .write_u64(C::N as u64)
+            .write_u64(C::R as u64)
+            .write_u64(unit_size as u64)
+            .write_u64(self.absorb_pos as u64)
+            .write_u64(self.squeeze_pos as u64);
+        U::write(self.sponge.as_ref(), &mut writer)?;
+        Ok(writer.finish())
+    }
+
+    fn import_state(state: &[u8]) -> Result<Self, IOPatternError> {
+        let mut reader = StateReader::new(state, StateKind::DuplexSponge)?;
+        let (n, r, size) = (reader.read_u64()?, reader.read_u64()?, reader.read_u64()?);
+        let expected = (C::N as u64, C::R as u64, unit_size(&C::default())? as u64);
+        if (n, r, size) != expected {
+            return Err(format!(
+                "Invalid state: expected a sponge of width {}, rate {} and units of {} bytes, got {}, {} and {}",
+                expected.0, expected.1, expected.2, n, r, size
+            )
+            .into());
+        }
+        let absorb_pos = reader.read_usize()?;
+        let squeeze_pos = reader.read_usize()?;
+        if absorb_pos > C::R || squeeze_pos > C::R {
+            return Err("Invalid state: position out of range".into());
+        }
+        // Start from the default state, that holds the permutation parameters, and overwrite the units.
+        let mut sponge = C::default();
+        let mut units = reader.read_rest();
+        U::read(&mut units, sponge.as_mut())?;
+        if !units.is_empty() {
+            return Err("Invalid state: trailing bytes".into());
+        }
+        Ok(Self {
+            sponge,
+            absorb_pos,
+            squeeze_pos,
+        })
+    }
 }
@@ -0,0 +1,338 @@
+//! Compatibility with [`merlin`](https://merlin.cool) transcripts.
+//!
+//! [`Strobe128`][`crate::hash::strobe::Strobe128`] is a minimal implementation of [STROBE](https://strobe.sourceforge.io/)
+//! at the 128-bit security level, supporting the operations used by merlin.
+//! [`MerlinStrobe`][`crate::hash::strobe::MerlinStrobe`] is a [`DuplexHash`][`crate::DuplexHash`] on top of it that reads the labels and the lengths of the messages
+//! from the IO Pattern, and reproduces exactly the transcript of a [`merlin::Transcript`] with:
+//! - the domain separator of the IO Pattern as label of `Transcript::new`;
+//! - `append_message(label, message)` for each absorb of `message.len()` bytes with label `label`;
+//! - `challenge_bytes(label, dest)` for each squeeze of `dest.len()` bytes with label `label`.
+//!
+//! This way, proofs generated with merlin (e.g. by dalek's Bulletproofs) can be checked using [`Arthur`][`crate::Arthur`].
+//!
+//! **Warning**: unlike the other hash functions in this crate,
+//! the initial state of [`MerlinStrobe`][`crate::hash::strobe::MerlinStrobe`] does not depend on the IV computed from the whole IO Pattern,
+//! but only on the labels of the operations performed so far, exactly as in merlin.
+//!
+//! [`merlin::Transcript`]: https://docs.rs/merlin/latest/merlin/struct.Transcript.html
+use std::collections::VecDeque;
+
+use zeroize::Zeroize;
+
+use super::keccak::AlignedKeccakState;
+use super::sponge::Sponge;
+use super::DuplexHash;
+
+/// STROBE rate for the 128-bit security level.
+const STROBE_R: usize = 166;
+
+const FLAG_I: u8 = 1;
+const FLAG_A: u8 = 1 << 1;
+const FLAG_C: u8 = 1 << 2;
+const FLAG_M: u8 = 1 << 4;
+const FLAG_K: u8 = 1 << 5;
+
+/// The protocol label used by merlin.
+const MERLIN_PROTOCOL_LABEL: &[u8] = b"Merlin v1.0";
+
+/// A STROBE context for the 128-bit security level.
+///
+/// Only the `meta-AD`, `AD`, `KEY`, `PRF`, and `RATCHET` operations are supported.
+#[derive(Clone, Default, Zeroize)]
+pub struct Strobe128 {
+    state: AlignedKeccakState<STROBE_R>,
+    pos: usize,
+    pos_begin: u8,
+    cur_flags: u8,
+}
+
+impl Strobe128 {
+    /// Initialize STROBE-128 with the given protocol label.
+    pub fn new(protocol_label: &[u8]) -> Self {
+        let mut strobe = Self::default();
+        strobe.state.as_mut()[0..6].copy_from_slice(&[1, STROBE_R as u8 + 2, 1, 0, 1, 96]);
+        strobe.state.as_mut()[6..18].copy_from_slice(b"STROBEv1.0.2");
+        strobe.state.permute();
+        strobe.meta_ad(protocol_label, false);
+        strobe
+    }
+
+    /// Absorb metadata.
+    pub fn meta_ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_M | FLAG_A, more);
+        self.absorb(data);
+    }
+
+    /// Absorb associated data.
+    pub fn ad(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_A, more);
+        self.absorb(data);
+    }
+
+    /// Squeeze pseudo-random bytes.
+    pub fn prf(&mut self, data: &mut [u8], more: bool) {
+        self.begin_op(FLAG_I | FLAG_A | FLAG_C, more);
+        self.squeeze(data);
+    }
+
+    /// Overwrite the state with a key.
+    pub fn key(&mut self, data: &[u8], more: bool) {
+        self.begin_op(FLAG_A | FLAG_C, more);
+        self.overwrite(data);
+    }
+
+    /// Overwrite `len` bytes of the state with zeros, preventing rollback.
+    pub fn ratchet(&mut self, len: usize, more: bool) {
+        self.begin_op(FLAG_C, more);
+        for _ in 0..len {
+            self.state.as_mut()[self.pos] = 0;
+            self.advance();
+        }
+    }
+
+    fn run_f(&mut self) {
+        let state = self.state.as_mut();
+        state[self.pos] ^= self.pos_begin;
+        state[self.pos + 1] ^= 0x04;
+        state[STROBE_R + 1] ^= 0x80;
+        self.state.permute();
+        self.pos = 0;
+        self.pos_begin = 0;
+    }
+
+    fn advance(&mut self) {
+        self.pos += 1;
+        if self.pos == STROBE_R {
+            self.run_f();
+        }
+    }
+
+    fn absorb(&mut self, data: &[u8]) {
+        for byte in data {
+            self.state.as_mut()[self.pos] ^= byte;
+            self.advance();
+        }
+    }
+
+    fn overwrite(&mut self, data: &[u8]) {
+        for byte in data {
+            self.state.as_mut()[self.pos] = *byte;
+            self.advance();
+        }
+    }
+
+    fn squeeze(&mut self, data: &mut [u8]) {
+        for byte in data {
+            *byte = self.state.as_ref()[self.pos];
+            self.state.as_mut()[self.pos] = 0;
+            self.advance();
+        }
+    }
+
+    fn begin_op(&mut self, flags: u8, more: bool) {
+        if more {
+            assert_eq!(
+                self.cur_flags, flags,
+                "Tried to continue operation {:#b} with flags {:#b}",
+                self.cur_flags, flags
+            );
+            return;
+        }
+
+        let old_begin = self.pos_begin;
+        // pos < STROBE_R, hence it fits in a byte
+        self.pos_begin = (self.pos + 1) as u8;
+        self.cur_flags = flags;
+        self.absorb(&[old_begin, flags]);
+
+        // Operations that use the cipher start on a fresh block.
+        let force_f = flags & (FLAG_C | FLAG_K) != 0;
+        if force_f && self.pos != 0 {
+            self.run_f();
+        }
+    }
+}
+
+impl core::fmt::Debug for Strobe128 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Ensure that the state isn't accidentally logged
+        write!(f, "Strobe128: STATE OMITTED")
+    }
+}
+
+/// Operations of the IO Pattern.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Kind {
+    Absorb,
+    Squeeze,
+    Ratchet,
+}
+
+/// An operation of the IO Pattern, with its label and length.
+type LabelledOp = (Kind, Vec<u8>, usize);
+
+/// A duplex sponge reproducing merlin transcripts.
+///
+/// Use it through an [`IOPattern`][`crate::IOPattern`], so that it knows the labels of the messages.
+#[derive(Clone, Default)]
+pub struct MerlinStrobe {
+    strobe: Strobe128,
+    /// The operations left in the IO Pattern, with their label and length.
+    ops: VecDeque<LabelledOp>,
+    /// The current operation.
+    kind: Option<Kind>,
+    /// The number of bytes left in the current operation.
+    remaining: usize,
+}
+
+impl MerlinStrobe {
+    /// Parse the IO Pattern *without* merging consecutive operations, since each of them carries its own label.
+    fn parse_io(io_pattern: &[u8]) -> (&[u8], VecDeque<LabelledOp>) {
+        let mut parts = io_pattern.split(|&b| b == 0);
+        let domain_separator = parts.next().unwrap_or_default();
+        let ops = parts
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let digits = part[1..].iter().take_while(|x| x.is_ascii_digit()).count();
+                let length = part[1..1 + digits]
+                    .iter()
+                    .fold(0, |acc, x| acc * 10 + (x - b'0') as usize);
+                let label = part[1 + digits..].to_vec();
+                let kind = match part[0] {
+                    b'A' => Kind::Absorb,
+                    b'S' => Kind::Squeeze,
+                    _ => Kind::Ratchet,
+                };
+                (kind, label, length)
+            })
+            .collect();
+        (domain_separator, ops)
+    }
+
+    /// Start a new operation, writing its label and length as metadata, as merlin does.
+    fn begin_op(&mut self, kind: Kind, len: usize) {
+        let (label, length) = match self.ops.front() {
+            Some((k, _, _)) if *k == kind => {
+                let (_, label, length) = self.ops.pop_front().unwrap();
+                (label, length)
+            }
+            // Without the IO Pattern, the operation is unlabelled.
+            _ => (Vec::new(), len),
+        };
+        self.strobe.meta_ad(&label, false);
+        self.strobe.meta_ad(&(length as u32).to_le_bytes(), true);
+        self.kind = Some(kind);
+        self.remaining = length;
+    }
+}
+
+impl Zeroize for MerlinStrobe {
+    fn zeroize(&mut self) {
+        self.strobe.zeroize();
+        self.ops.clear();
+        self.kind = None;
+        self.remaining = 0;
+    }
+}
+
+impl DuplexHash<u8> for MerlinStrobe {
+    /// The security level of Strobe-128.
+    const SECURITY_LEVEL: usize = 128;
+
+    /// Initialize a transcript with the IV as label.
+    /// Prefer [`DuplexHash::with_io_pattern`], which reproduces merlin's transcripts.
+    fn new(iv: [u8; 32]) -> Self {
+        Self {
+            strobe: Strobe128::new(&iv),
+            ..Default::default()
+        }
+    }
+
+    /// Reproduce `merlin::Transcript::new`, using the domain separator as label.
+    fn with_io_pattern(io_pattern: &[u8], _iv: [u8; 32]) -> Self {
+        let (domain_separator, ops) = Self::parse_io(io_pattern);
+        let mut strobe = Strobe128::new(MERLIN_PROTOCOL_LABEL);
+        strobe.meta_ad(b"dom-sep", false);
+        strobe.meta_ad(&(domain_separator.len() as u32).to_le_bytes(), true);
+        strobe.ad(domain_separator, false);
+        Self {
+            strobe,
+            ops,
+            kind: None,
+            remaining: 0,
+        }
+    }
+
+    fn absorb_unchecked(&mut self, mut input: &[u8]) -> &mut Self {
+        while !input.is_empty() {
+            let more = self.kind == Some(Kind::Absorb) && self.remaining > 0;
+            if !more {
+                self.begin_op(Kind::Absorb, input.len());
+            }
+            let len = usize::min(self.remaining, input.len());
+            self.strobe.ad(&input[..len], more);
+            self.remaining -= len;
+            input = &input[len..];
+        }
+        self
+    }
+
+    fn squeeze_unchecked(&mut self, mut output: &mut [u8]) -> &mut Self {
+        while !output.is_empty() {
+            let more = self.kind == Some(Kind::Squeeze) && self.remaining > 0;
+            if !more {
+                self.begin_op(Kind::Squeeze, output.len());
+            }
+            let len = usize::min(self.remaining, output.len());
+            let (chunk, rest) = output.split_at_mut(len);
+            self.strobe.prf(chunk, more);
+            self.remaining -= len;
+            output = rest;
+        }
+        self
+    }
+
+    fn ratchet_unchecked(&mut self) -> &mut Self {
+        if let Some((Kind::Ratchet, _, _)) = self.ops.front() {
+            self.ops.pop_front();
+        }
+        self.strobe.ratchet(32, false);
+        self.kind = Some(Kind::Ratchet);
+        self.remaining = 0;
+        self
+    }
+}
+
+#[test]
+fn test_merlin_compatibility() {
+    use crate::{ByteChallenges, ByteReader, ByteWriter, IOPattern};
+
+    let mut transcript = merlin::Transcript::new(b"test protocol");
+    transcript.append_message(b"some label", b"some data");
+    transcript.append_message(b"another label", b"some other data");
+    let mut expected = [0u8; 32];
+    transcript.challenge_bytes(b"challenge", &mut expected);
+    let mut expected_long = [0u8; 500];
+    transcript.challenge_bytes(b"long challenge", &mut expected_long);
+
+    let io = IOPattern::<MerlinStrobe>::new("test protocol")
+        .absorb(9, "some label")
+        .absorb(15, "another label")
+        .squeeze(32, "challenge")
+        .squeeze(500, "long challenge");
+
+    // Consecutive messages can be added at once, or streamed.
+    let mut prover = io.to_merlin();
+    prover.add_bytes(b"some datasome other").unwrap();
+    prover.add_bytes(b" data").unwrap();
+    assert_eq!(prover.challenge_bytes::<32>().unwrap(), expected);
+    let mut challenge = [0u8; 500];
+    prover.fill_challenge_bytes(&mut challenge[..100]).unwrap();
+    prover.fill_challenge_bytes(&mut challenge[100..]).unwrap();
+    assert_eq!(challenge, expected_long);
+
+    let mut verifier = io.to_arthur(prover.transcript());
+    assert_eq!(&verifier.next_bytes::<9>().unwrap(), b"some data");
+    assert_eq!(&verifier.next_bytes::<15>().unwrap(), b"some other data");
+    assert_eq!(verifier.challenge_bytes::<32>().unwrap(), expected);
+}
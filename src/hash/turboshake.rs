@@ -0,0 +1,150 @@
+//! **Warning**: this function is not TurboSHAKE.
+//! Despite internally we use the same permutation function, Keccak-p\[1600, 12\],
+//! we build a duplex sponge in overwrite mode
+//! on the top of it using the `DuplexSponge` trait.
+use super::sponge::{DuplexSponge, Sponge};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Number of rounds of the Keccak-p permutation used by TurboSHAKE.
+const ROUNDS: usize = 12;
+
+/// A duplex sponge based on the permutation [`keccak::p1600`] reduced to 12 rounds,
+/// with capacity 256 bits (rate 168 bytes), as in TurboSHAKE128.
+pub type TurboShake128 = DuplexSponge<TurboShakeState<168>>;
+
+/// A duplex sponge based on the permutation [`keccak::p1600`] reduced to 12 rounds,
+/// with capacity 512 bits (rate 136 bytes), as in TurboSHAKE256.
+pub type TurboShake256 = DuplexSponge<TurboShakeState<136>>;
+
+/// The default TurboSHAKE-based sponge, with 128 bits of security.
+pub type TurboShake = TurboShake128;
+
+fn transmute_state<const R: usize>(st: &mut TurboShakeState<R>) -> &mut [u64; 25] {
+    unsafe { &mut *(st as *mut TurboShakeState<R> as *mut [u64; 25]) }
+}
+
+/// A 200-byte Keccak state with rate `R`, always 8-byte aligned
+/// to make pointers to it safely convertible to pointers to [u64; 25].
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+#[repr(align(8))]
+pub struct TurboShakeState<const R: usize>([u8; 200]);
+
+impl<const R: usize> Sponge for TurboShakeState<R> {
+    type U = u8;
+    const N: usize = 200;
+    const R: usize = R;
+    const SECURITY_LEVEL: usize = (Self::N - R) * 4;
+
+    fn new(tag: [u8; 32]) -> Self {
+        assert!(
+            R + 32 <= Self::N,
+            "The capacity must be large enough to hold the IV."
+        );
+        let mut state = Self::default();
+        state.0[R..R + 32].copy_from_slice(&tag);
+        state
+    }
+
+    fn permute(&mut self) {
+        keccak::p1600(transmute_state(self), ROUNDS);
+    }
+}
+
+impl<const R: usize> Default for TurboShakeState<R> {
+    fn default() -> Self {
+        Self([0u8; 200])
+    }
+}
+
+impl<const R: usize> AsRef<[u8]> for TurboShakeState<R> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const R: usize> AsMut<[u8]> for TurboShakeState<R> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// TurboSHAKE as specified in RFC 9861, built on the same state, for known-answer tests on the permutation.
+#[cfg(test)]
+fn turboshake<const R: usize>(message: &[u8], domain: u8, output: &mut [u8]) {
+    let mut state = TurboShakeState::<R>::default();
+    let mut padded = message.to_vec();
+    padded.push(domain);
+    padded.resize(padded.len().div_ceil(R) * R, 0);
+    *padded.last_mut().unwrap() ^= 0x80;
+    for block in padded.chunks(R) {
+        state.0[..R]
+            .iter_mut()
+            .zip(block)
+            .for_each(|(s, m)| *s ^= m);
+        state.permute();
+    }
+    for chunk in output.chunks_mut(R) {
+        chunk.copy_from_slice(&state.0[..chunk.len()]);
+        state.permute();
+    }
+}
+
+#[test]
+fn test_turboshake_kat() {
+    // Test vectors from RFC 9861, Section 5.
+    let ptn = |n: usize| (0..n).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+
+    let mut output = [0u8; 32];
+    turboshake::<168>(b"", 0x1f, &mut output);
+    assert_eq!(
+        hex::encode(output),
+        "1e415f1c5983aff2169217277d17bb538cd945a397ddec541f1ce41af2c1b74c"
+    );
+    turboshake::<168>(&ptn(17), 0x1f, &mut output);
+    assert_eq!(
+        hex::encode(output),
+        "9c97d036a3bac819db70ede0ca554ec6e4c2a1a4ffbfd9ec269ca6a111161233"
+    );
+    let mut long_output = [0u8; 10032];
+    turboshake::<168>(b"", 0x1f, &mut long_output);
+    assert_eq!(
+        hex::encode(&long_output[10000..]),
+        "a3b9b0385900ce761f22aed548e754da10a5242d62e8c658e3f3a923a7555607"
+    );
+
+    let mut output = [0u8; 64];
+    turboshake::<136>(b"", 0x1f, &mut output);
+    assert_eq!(
+        hex::encode(output),
+        "367a329dafea871c7802ec67f905ae13c57695dc2c6663c61035f59a18f8e7db\
+         11edc0e12e91ea60eb6b32df06dd7f002fbafabb6e13ec1cc20d995547600db0"
+    );
+    turboshake::<136>(&ptn(17), 0x1f, &mut output);
+    assert_eq!(
+        hex::encode(output),
+        "b3bab0300e6a191fbe6137939835923578794ea54843f5011090fa2f3780a9e5\
+         cb22c59d78b40a0fbff9e672c0fbe0970bd2c845091c6044d687054da5d8e9c7"
+    );
+}
+
+#[test]
+fn test_turboshake_duplex_kat() {
+    use crate::DuplexHash;
+
+    let iv = core::array::from_fn(|i| i as u8);
+    let mut output = [0u8; 32];
+    TurboShake128::new(iv)
+        .absorb_unchecked(b"nimue")
+        .squeeze_unchecked(&mut output);
+    assert_eq!(
+        hex::encode(output),
+        "8b893ca6ef838bee4d2648cbb284f10a886f05b79d7b6df515917e2b8e0e8eea"
+    );
+    TurboShake256::new(iv)
+        .absorb_unchecked(b"nimue")
+        .squeeze_unchecked(&mut output);
+    assert_eq!(
+        hex::encode(output),
+        "df1d303b30809c6287f97d11bc049eb4e7b292e2a5fa43542005498cb00ab605"
+    );
+}
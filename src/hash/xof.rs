@@ -0,0 +1,117 @@
+//! A bridge to our sponge interface for hash functions with an extendable output (XOF),
+//! such as SHAKE and Blake3.
+//!
+//! Informally, each instance of the underlying hash function is keyed with a 32-byte chaining value `cv`
+//! (initially, the IV) and then fed with the data absorbed so far:
+//! - `absorb_unchecked` feeds the hasher directly, and thus satisfies streaming;
+//! - the first `squeeze_unchecked` finalizes (a copy of) the hasher into an XOF reader.
+//!     The first 32 bytes of the output stream are reserved as the next chaining value,
+//!     the remaining ones are returned as challenges. Subsequent squeezes keep on reading from the same stream:
+//!     ```text
+//!     squeeze(1); squeeze(1); squeeze(1) = squeeze(3);
+//!     ```
+//! - `absorb_unchecked` after a squeeze, and `ratchet_unchecked`, restart the hasher keyed with the next chaining value.
+use digest::{ExtendableOutput, Update, XofReader};
+use zeroize::Zeroize;
+
+use super::DuplexHash;
+
+/// A Bridge to our sponge interface for hash functions implementing [`digest::ExtendableOutput`].
+#[derive(Clone)]
+pub struct XofBridge<X: ExtendableOutput + Update + Default + Clone> {
+    /// The underlying hasher, keyed with the current chaining value.
+    hasher: X,
+    /// Chaining value for the next instance of the hasher.
+    cv: [u8; 32],
+    /// Current operation, keeping state between absorb and squeeze
+    /// across multiple calls when streaming.
+    mode: Mode<X::Reader>,
+}
+
+#[derive(Clone)]
+enum Mode<R> {
+    Absorb,
+    Squeeze(R),
+}
+
+impl<X: ExtendableOutput + Update + Default + Clone> XofBridge<X> {
+    /// Restart the hasher, keyed with the chaining value.
+    fn restart(&mut self) {
+        self.hasher = X::default();
+        self.hasher.update(&self.cv);
+        self.mode = Mode::Absorb;
+    }
+
+    /// Finalize the absorbed data into an output stream, and store the next chaining value.
+    fn squeeze_start(&mut self) {
+        if let Mode::Absorb = self.mode {
+            let mut reader = self.hasher.clone().finalize_xof();
+            reader.read(&mut self.cv);
+            self.mode = Mode::Squeeze(reader);
+        }
+    }
+}
+
+impl<X: ExtendableOutput + Update + Default + Clone> Zeroize for XofBridge<X> {
+    fn zeroize(&mut self) {
+        self.cv.zeroize();
+        self.hasher = X::default();
+        self.mode = Mode::Absorb;
+    }
+}
+
+impl<X: ExtendableOutput + Update + Default + Clone> Drop for XofBridge<X> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<X: ExtendableOutput + Update + Default + Clone> Default for XofBridge<X> {
+    fn default() -> Self {
+        let mut bridge = Self {
+            hasher: X::default(),
+            cv: [0u8; 32],
+            mode: Mode::Absorb,
+        };
+        bridge.restart();
+        bridge
+    }
+}
+
+impl<X> DuplexHash<u8> for XofBridge<X>
+where
+    X: ExtendableOutput + Update + Default + Clone,
+    X::Reader: Clone,
+{
+    /// Half the size of the 32-byte chaining value.
+    const SECURITY_LEVEL: usize = 128;
+
+    fn new(tag: [u8; 32]) -> Self {
+        let mut bridge = Self::default();
+        bridge.cv = tag;
+        bridge.restart();
+        bridge
+    }
+
+    fn absorb_unchecked(&mut self, input: &[u8]) -> &mut Self {
+        if let Mode::Squeeze(_) = self.mode {
+            self.restart();
+        }
+        self.hasher.update(input);
+        self
+    }
+
+    fn squeeze_unchecked(&mut self, output: &mut [u8]) -> &mut Self {
+        self.squeeze_start();
+        if let Mode::Squeeze(reader) = &mut self.mode {
+            reader.read(output);
+        }
+        self
+    }
+
+    fn ratchet_unchecked(&mut self) -> &mut Self {
+        self.squeeze_start();
+        self.restart();
+        self
+    }
+}
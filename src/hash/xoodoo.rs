@@ -0,0 +1,127 @@
+//! **Warning**: this function is not Xoodyak.
+//! Despite internally we use the same permutation function,
+//! we build a duplex sponge in overwrite mode
+//! on the top of it using the `DuplexSponge` trait.
+use super::sponge::{DuplexSponge, Sponge};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// A duplex sponge based on the Xoodoo\[12\] permutation over a 384-bit state,
+/// with a rate of 128 bits and a capacity of 256 bits, using [`DuplexSponge`].
+pub type Xoodoo = DuplexSponge<XoodooState>;
+
+/// Round constants of the 12-round Xoodoo permutation.
+const ROUND_CONSTANTS: [u32; 12] = [
+    0x058, 0x038, 0x3c0, 0x0d0, 0x120, 0x014, 0x060, 0x02c, 0x380, 0x0f0, 0x1a0, 0x012,
+];
+
+/// The Xoodoo\[12\] permutation, over three planes of four 32-bit lanes.
+/// Lane `x` of plane `y` is at position `4 * y + x`.
+fn xoodoo_p12(a: &mut [u32; 12]) {
+    for c in ROUND_CONSTANTS {
+        // theta
+        let p: [u32; 4] = core::array::from_fn(|x| a[x] ^ a[x + 4] ^ a[x + 8]);
+        let e: [u32; 4] = core::array::from_fn(|x| {
+            let p = p[(x + 3) % 4];
+            p.rotate_left(5) ^ p.rotate_left(14)
+        });
+        a.iter_mut()
+            .enumerate()
+            .for_each(|(i, lane)| *lane ^= e[i % 4]);
+        // rho-west
+        a[4..8].rotate_right(1);
+        a[8..12]
+            .iter_mut()
+            .for_each(|lane| *lane = lane.rotate_left(11));
+        // iota
+        a[0] ^= c;
+        // chi
+        for x in 0..4 {
+            let (a0, a1, a2) = (a[x], a[x + 4], a[x + 8]);
+            a[x] ^= !a1 & a2;
+            a[x + 4] ^= !a2 & a0;
+            a[x + 8] ^= !a0 & a1;
+        }
+        // rho-east
+        a[4..8]
+            .iter_mut()
+            .for_each(|lane| *lane = lane.rotate_left(1));
+        a[8..12].rotate_right(2);
+        a[8..12]
+            .iter_mut()
+            .for_each(|lane| *lane = lane.rotate_left(8));
+    }
+}
+
+/// The 48-byte Xoodoo state, with lanes encoded in little-endian order.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct XoodooState([u8; 48]);
+
+impl Sponge for XoodooState {
+    type U = u8;
+    const N: usize = 48;
+    const R: usize = 16;
+    const SECURITY_LEVEL: usize = (Self::N - Self::R) * 4;
+
+    fn new(tag: [u8; 32]) -> Self {
+        let mut state = Self::default();
+        state.0[Self::R..Self::R + 32].copy_from_slice(&tag);
+        state
+    }
+
+    fn permute(&mut self) {
+        let mut lanes = [0u32; 12];
+        for (lane, bytes) in lanes.iter_mut().zip(self.0.chunks_exact(4)) {
+            *lane = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+        xoodoo_p12(&mut lanes);
+        for (lane, bytes) in lanes.iter().zip(self.0.chunks_exact_mut(4)) {
+            bytes.copy_from_slice(&lane.to_le_bytes());
+        }
+        lanes.zeroize();
+    }
+}
+
+impl Default for XoodooState {
+    fn default() -> Self {
+        Self([0u8; Self::N])
+    }
+}
+
+impl AsRef<[u8]> for XoodooState {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for XoodooState {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+#[test]
+fn test_xoodoo_kat() {
+    // Xoodoo[12] applied to the all-zero state.
+    let mut state = XoodooState::default();
+    state.permute();
+    assert_eq!(
+        hex::encode(state.0),
+        "8dd8d589bffc63a9192d231b14a0a5ff0681b136fec1c7afbe7ce5aebd4075a7\
+         70e8862ec9b7f5fef2ad4f8b62404f5e"
+    );
+}
+
+#[test]
+fn test_xoodoo_duplex_kat() {
+    use crate::DuplexHash;
+
+    let iv = core::array::from_fn(|i| i as u8);
+    let mut output = [0u8; 32];
+    Xoodoo::new(iv)
+        .absorb_unchecked(b"nimue")
+        .squeeze_unchecked(&mut output);
+    assert_eq!(
+        hex::encode(output),
+        "e8a2c836e2640f28dbbe6259330ad337b53cc3a8e10e7b0e49fee44c2b9c97f7"
+    );
+}
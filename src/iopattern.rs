@@ -41,9 +41,52 @@ where
     H: DuplexHash<U>,
 {
     io: String,
+    point_encoding: PointEncoding,
     _hash: PhantomData<(H, U)>,
 }
 
+/// The encoding of group elements in byte-oriented protocol transcripts.
+///
+/// It is set once for the whole protocol with [`IOPattern::with_point_encoding`],
+/// and used by the arkworks plugin both to size the IO Pattern and to write and read points,
+/// so that the prover and the verifier cannot disagree on it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PointEncoding {
+    /// The compressed encoding of arkworks.
+    #[default]
+    Compressed,
+    /// The uncompressed encoding of arkworks: larger, but decoding does not compute square roots.
+    Uncompressed,
+    /// A single affine coordinate: $x$ for short Weierstrass curves,
+    /// and the $u$-coordinate of the birationally equivalent Montgomery curve for twisted Edwards curves.
+    ///
+    /// Points are only recovered up to sign: the protocol must be invariant under negation, as X25519 is.
+    /// The identity cannot be encoded.
+    XOnly,
+}
+
+impl PointEncoding {
+    /// The name of the encoding, prepended to the labels of points in the IO Pattern.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Compressed => "compressed",
+            Self::Uncompressed => "uncompressed",
+            Self::XOnly => "x-only",
+        }
+    }
+
+    pub(crate) fn id(self) -> u8 {
+        self as u8
+    }
+
+    pub(crate) fn from_id(id: u8) -> Result<Self, IOPatternError> {
+        [Self::Compressed, Self::Uncompressed, Self::XOnly]
+            .into_iter()
+            .find(|encoding| encoding.id() == id)
+            .ok_or_else(|| format!("Invalid point encoding {}", id).into())
+    }
+}
+
 /// Sponge operations.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub(crate) enum Op {
@@ -66,7 +109,7 @@ pub(crate) enum Op {
 
 impl Op {
     /// Create a new OP from the portion of a tag.
-    fn new(id: char, count: Option<usize>) -> Result<Self, IOPatternError> {
+    pub(crate) fn new(id: char, count: Option<usize>) -> Result<Self, IOPatternError> {
         match (id, count) {
             ('A', Some(c)) if c > 0 => Ok(Op::Absorb(c)),
             ('R', None) | ('R', Some(0)) => Ok(Op::Ratchet),
@@ -76,14 +119,64 @@ impl Op {
     }
 }
 
+/// Check that the next operation of `stack` is an absorption (resp. squeeze) of (at least) `len` elements,
+/// as in [`Safe::absorb`][`crate::Safe::absorb`] (resp. [`Safe::squeeze`][`crate::Safe::squeeze`]),
+/// or a ratchet, and pop it.
+///
+/// On mismatch, the stack is cleared.
+#[cfg(any(feature = "ark-r1cs-std", feature = "halo2"))]
+pub(crate) fn pop_op(stack: &mut VecDeque<Op>, expected: Op) -> Result<(), IOPatternError> {
+    let remaining = match (stack.pop_front(), expected) {
+        (Some(Op::Absorb(length)), Op::Absorb(len)) if length >= len => Op::Absorb(length - len),
+        (Some(Op::Squeeze(length)), Op::Squeeze(len)) if length >= len => Op::Squeeze(length - len),
+        (Some(Op::Ratchet), Op::Ratchet) => return Ok(()),
+        (op, _) => {
+            stack.clear();
+            return Err(format!("Invalid tag. Got {:?}, expected {:?}", expected, op).into());
+        }
+    };
+    if !matches!(remaining, Op::Absorb(0) | Op::Squeeze(0)) {
+        stack.push_front(remaining);
+    }
+    Ok(())
+}
+
 impl<H: DuplexHash<U>, U: Unit> IOPattern<H, U> {
     fn from_string(io: String) -> Self {
         Self {
             io,
+            point_encoding: PointEncoding::default(),
             _hash: PhantomData,
         }
     }
 
+    /// Append `op` to the IO Pattern, keeping its point encoding.
+    fn push_op(self, op: &str) -> Self {
+        Self {
+            io: self.io + SEP_BYTE + op,
+            ..self
+        }
+    }
+
+    /// Set the encoding of the group elements of the protocol, [`PointEncoding::Compressed`] by default.
+    ///
+    /// It must be set before any operation is added.
+    pub fn with_point_encoding(self, point_encoding: PointEncoding) -> Self {
+        assert!(
+            !self.io.contains(SEP_BYTE),
+            "The point encoding must be set before adding operations."
+        );
+        Self {
+            point_encoding,
+            ..self
+        }
+    }
+
+    /// The encoding of the group elements of the protocol.
+    pub fn point_encoding(&self) -> PointEncoding {
+        self.point_encoding
+    }
+
     /// Create a new IOPattern with the domain separator.
     pub fn new(domsep: &str) -> Self {
         assert!(
@@ -108,7 +201,7 @@ impl<H: DuplexHash<U>, U: Unit> IOPattern<H, U> {
             "Label cannot start with a digit."
         );
 
-        Self::from_string(self.io + SEP_BYTE + &format!("A{}", count) + label)
+        self.push_op(&format!("A{}{}", count, label))
     }
 
     /// Squeeze `count` native elements.
@@ -126,12 +219,12 @@ impl<H: DuplexHash<U>, U: Unit> IOPattern<H, U> {
             "Label cannot start with a digit."
         );
 
-        Self::from_string(self.io + SEP_BYTE + &format!("S{}", count) + label)
+        self.push_op(&format!("S{}{}", count, label))
     }
 
     /// Ratchet the state.
     pub fn ratchet(self) -> Self {
-        Self::from_string(self.io + SEP_BYTE + "R")
+        self.push_op("R")
     }
 
     /// Return the IO Pattern as bytes.
@@ -45,7 +45,9 @@
 //! # Batteries included
 //! The library comes with support for algebraic objects over arkworks and zkcrypto:
 //! - with feature flag `--feature=ark`, the module [`plugins::ark`] provides extension traits for arkworks fields and groups;
-//! - with feature flag `--feature=group`, the module [`plugins::group`] provides extension traits for zkcrypto's field and group traits.
+//! - with feature flag `--feature=group`, the module [`plugins::group`] provides extension traits for zkcrypto's field and group traits;
+//! - the module [`plugins::small_fields`] provides native support for the small prime fields used in STARKs, and for the hashes defined over them.
+//! - the module [`plugins::pow`] provides proof-of-work challenges; with feature flag `--feature=rayon`, the prover grinds them on multiple threads.
 //! See the [`plugins`] module for more information.
 //!
 //!
@@ -121,6 +123,8 @@ This crate doesn't support big-endian targets.
 
 /// Verifier state and transcript deserialization.
 mod arthur;
+/// Versioned serialization of the prover and hash states.
+mod checkpoint;
 /// Built-in proof results.
 mod errors;
 /// Hash functions traits and implementations.
@@ -142,8 +146,8 @@ pub mod traits;
 
 pub use arthur::Arthur;
 pub use errors::{IOPatternError, ProofError, ProofResult};
-pub use hash::{legacy::DigestBridge, DuplexHash, Unit};
-pub use iopattern::IOPattern;
+pub use hash::{legacy::DigestBridge, xof::XofBridge, DuplexHash, Unit};
+pub use iopattern::{IOPattern, PointEncoding};
 pub use merlin::Merlin;
 pub use safe::Safe;
 pub use traits::*;
@@ -1,5 +1,7 @@
 use rand::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
 
+use crate::checkpoint::{StateKind, StateReader, StateWriter};
 use crate::hash::Unit;
 use crate::{ByteWriter, IOPattern, Safe, UnitTranscript};
 
@@ -13,6 +15,7 @@ use super::{DefaultHash, DefaultRng, IOPatternError};
 /// it is seeded by a cryptographic random number generator (by default, [`rand::rngs::OsRng`]).
 ///
 /// Every time the prover's sponge is squeeze, the state of the sponge is ratcheted, so that it can't be inverted and the randomness recovered.
+#[derive(Clone)]
 pub(crate) struct ProverRng<R: RngCore + CryptoRng> {
     /// The sponge that is used to generate the random coins.
     pub(crate) sponge: Keccak,
@@ -178,6 +181,52 @@ where
     pub fn transcript(&self) -> &[u8] {
         self.transcript.as_slice()
     }
+
+    /// Export the prover state: the verifier's sponge, the operations left to perform,
+    /// the prover's private sponge, and the transcript so far.
+    ///
+    /// This allows to checkpoint a protocol execution and resume it later (or on another machine)
+    /// with [`Merlin::import_state`].
+    /// The output contains the prover's secret state: it is zeroized on drop and must be stored securely.
+    ///
+    /// ```
+    /// # use nimue::*;
+    ///
+    /// let io = IOPattern::<DefaultHash>::new("📝").absorb(8, "ingredients").squeeze(16, "taste");
+    /// let mut merlin = io.to_merlin();
+    /// merlin.add_bytes(b"1tbsp:3l").unwrap();
+    /// let state = merlin.export_state().unwrap();
+    ///
+    /// let mut resumed = Merlin::<DefaultHash>::import_state(&state, DefaultRng::default()).unwrap();
+    /// assert_eq!(resumed.transcript(), b"1tbsp:3l");
+    /// assert_eq!(resumed.challenge_bytes::<16>().unwrap(), merlin.challenge_bytes::<16>().unwrap());
+    /// ```
+    pub fn export_state(&self) -> Result<Zeroizing<Vec<u8>>, IOPatternError> {
+        let safe = self.safe.export_state()?;
+        let rng = self.rng.sponge.export_state()?;
+        let len = 16 + safe.len() + rng.len() + self.transcript.len();
+        let mut writer = StateWriter::new(StateKind::Merlin, len);
+        writer
+            .write_prefixed(&safe)
+            .write_prefixed(&rng)
+            .write_bytes(&self.transcript);
+        Ok(writer.finish())
+    }
+
+    /// Load a prover state exported with [`Merlin::export_state`].
+    ///
+    /// The private sponge is additionally re-seeded by `csrng` every time the prover requests randomness.
+    pub fn import_state(state: &[u8], csrng: R) -> Result<Self, IOPatternError> {
+        let mut reader = StateReader::new(state, StateKind::Merlin)?;
+        let safe = Safe::import_state(reader.read_prefixed()?)?;
+        let sponge = Keccak::import_state(reader.read_prefixed()?)?;
+        let transcript = reader.read_rest().to_vec();
+        Ok(Self {
+            rng: ProverRng { sponge, csrng },
+            safe,
+            transcript,
+        })
+    }
 }
 
 impl<H, U, R> UnitTranscript<U> for Merlin<H, U, R>
@@ -213,6 +262,23 @@ where
 
 impl<R: RngCore + CryptoRng> CryptoRng for ProverRng<R> {}
 
+/// Cloning the prover state also clones the state of its random number generator.
+/// The two copies will produce the same random coins, unless `R` draws fresh entropy (as [`rand::rngs::OsRng`] does).
+impl<H, U, R> Clone for Merlin<H, U, R>
+where
+    U: Unit,
+    H: DuplexHash<U>,
+    R: RngCore + CryptoRng + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rng: self.rng.clone(),
+            safe: self.safe.clone(),
+            transcript: self.transcript.clone(),
+        }
+    }
+}
+
 impl<H, U, R> core::fmt::Debug for Merlin<H, U, R>
 where
     U: Unit,
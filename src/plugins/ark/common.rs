@@ -1,12 +1,17 @@
 use std::io;
 
-use ark_ec::{AffineRepr, CurveGroup};
-use ark_ff::{Field, Fp, FpConfig, PrimeField};
+use ark_ff::{BigInteger, Field, Fp, FpConfig, PrimeField};
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
 use rand::{CryptoRng, RngCore};
 
-use super::{FieldChallenges, FieldPublic, GroupPublic};
-use crate::plugins::bytes_uniform_modp;
+use super::{
+    AlgebraicPoint, EncodableCurve, FieldChallenges, FieldPublic, GroupPublic,
+    NonNativeFieldChallenges,
+};
+use crate::plugins::{
+    bytes_uniform_modp, challenge_short_integers, uniform_bytes_from_units,
+    units_for_challenge_bytes,
+};
 use crate::{
     Arthur, ByteChallenges, BytePublic, DuplexHash, IOPatternError, Merlin, ProofError,
     ProofResult, Unit, UnitTranscript,
@@ -42,19 +47,43 @@ impl From<SerializationError> for ProofError {
 
 // Bytes <-> Field elements interactions:
 
-impl<T, G> GroupPublic<G> for T
+/// Points are encoded with the [`PointEncoding`][`crate::PointEncoding`] of the IO Pattern.
+fn public_encoded_points<G: EncodableCurve>(
+    transcript: &mut impl UnitTranscript<u8>,
+    encoding: crate::PointEncoding,
+    input: &[G],
+) -> ProofResult<Vec<u8>> {
+    let mut buf = Vec::with_capacity(input.len() * encoding.size::<G>());
+    for i in input {
+        encoding.encode(i, &mut buf)?;
+    }
+    Ok(transcript.public_bytes(&buf).map(|()| buf)?)
+}
+
+impl<H, R, G> GroupPublic<G> for Merlin<H, u8, R>
 where
-    G: CurveGroup,
-    T: UnitTranscript<u8>,
+    H: DuplexHash,
+    R: RngCore + CryptoRng,
+    G: EncodableCurve,
 {
     type Repr = Vec<u8>;
 
     fn public_points(&mut self, input: &[G]) -> ProofResult<Self::Repr> {
-        let mut buf = Vec::new();
-        for i in input {
-            i.serialize_compressed(&mut buf)?;
-        }
-        Ok(self.public_bytes(&buf).map(|()| buf)?)
+        let encoding = self.safe.point_encoding();
+        public_encoded_points(self, encoding, input)
+    }
+}
+
+impl<H, G> GroupPublic<G> for Arthur<'_, H, u8>
+where
+    H: DuplexHash,
+    G: EncodableCurve,
+{
+    type Repr = Vec<u8>;
+
+    fn public_points(&mut self, input: &[G]) -> ProofResult<Self::Repr> {
+        let encoding = self.safe.point_encoding();
+        public_encoded_points(self, encoding, input)
     }
 }
 
@@ -77,32 +106,110 @@ where
 
 impl<F, T> FieldChallenges<F> for T
 where
-    F: PrimeField,
+    F: Field,
     T: ByteChallenges,
 {
+    /// Each coefficient over the base prime field is sampled from bytes independently.
     fn fill_challenge_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
-        let mut buf = vec![0u8; bytes_uniform_modp(F::MODULUS_BIT_SIZE)];
+        let mut buf = vec![0u8; bytes_uniform_modp(F::BasePrimeField::MODULUS_BIT_SIZE)];
+        let mut coefficients = Vec::with_capacity(F::extension_degree() as usize);
 
         for o in output.iter_mut() {
-            self.fill_challenge_bytes(&mut buf)?;
-            *o = F::from_be_bytes_mod_order(&buf).into();
+            coefficients.clear();
+            for _ in 0..F::extension_degree() {
+                self.fill_challenge_bytes(&mut buf)?;
+                coefficients.push(F::BasePrimeField::from_be_bytes_mod_order(&buf));
+            }
+            *o = F::from_base_prime_field_elems(coefficients.iter().copied()).unwrap();
         }
         Ok(())
     }
+
+    fn fill_challenge_short_scalars(&mut self, output: &mut [F], bits: usize) -> ProofResult<()> {
+        fill_short_scalars(self, output, bits)
+    }
+}
+
+/// Squeeze scalars of `bits` bits from bytes.
+/// In extension fields, short scalars lie in the base prime field.
+fn fill_short_scalars<F: Field>(
+    transcript: &mut impl ByteChallenges,
+    output: &mut [F],
+    bits: usize,
+) -> ProofResult<()> {
+    let modulus_bits = F::BasePrimeField::MODULUS_BIT_SIZE as usize;
+    let integers = challenge_short_integers(transcript, output.len(), bits, modulus_bits)?;
+    for (o, integer) in output.iter_mut().zip(integers) {
+        *o = F::from_base_prime_field(F::BasePrimeField::from_be_bytes_mod_order(&integer));
+    }
+    Ok(())
+}
+
+/// Squeeze one scalar per short scalar, and keep the `bits` least significant bits
+/// of its first coefficient over the base prime field.
+///
+/// The result is $2^{\text{bits}} / p$-close to uniform.
+pub(super) fn fill_truncated_short_scalars<F, T>(
+    transcript: &mut T,
+    output: &mut [F],
+    bits: usize,
+) -> ProofResult<()>
+where
+    F: Field,
+    T: FieldChallenges<F> + ?Sized,
+{
+    let modulus_bits = F::BasePrimeField::MODULUS_BIT_SIZE as usize;
+    if bits == 0 || bits >= modulus_bits {
+        return Err(IOPatternError::from(format!(
+            "Short scalars must have between 1 and {} bits, got {}",
+            modulus_bits - 1,
+            bits
+        ))
+        .into());
+    }
+    transcript.fill_challenge_scalars(output)?;
+    for o in output.iter_mut() {
+        let coefficient = o.to_base_prime_field_elements().next().unwrap();
+        let mut bytes = coefficient.into_bigint().to_bytes_le();
+        bytes.truncate(bits.div_ceil(8));
+        *bytes.last_mut().unwrap() &= 0xff >> (bytes.len() * 8 - bits);
+        *o = F::from_base_prime_field(F::BasePrimeField::from_le_bytes_mod_order(&bytes));
+    }
+    Ok(())
 }
 
 // Field <-> Field interactions:
+//
+// Elements of extensions of the field of the sponge are absorbed and squeezed
+// as their coefficients over the base prime field.
+
+/// The coefficients over the base prime field of `input`.
+fn to_base_prime_field_units<F: Field>(input: &[F]) -> Vec<F::BasePrimeField> {
+    input
+        .iter()
+        .flat_map(|x| x.to_base_prime_field_elements())
+        .collect()
+}
 
-impl<H, R, C, const N: usize> FieldPublic<Fp<C, N>> for Merlin<H, Fp<C, N>, R>
+/// Fill `output` with the elements of `F` whose coefficients over the base prime field are `units`.
+pub(super) fn from_base_prime_field_units<F: Field>(units: &[F::BasePrimeField], output: &mut [F]) {
+    let degree = F::extension_degree() as usize;
+    for (o, coefficients) in output.iter_mut().zip(units.chunks(degree)) {
+        *o = F::from_base_prime_field_elems(coefficients.iter().copied()).unwrap();
+    }
+}
+
+impl<H, R, C, F, const N: usize> FieldPublic<F> for Merlin<H, Fp<C, N>, R>
 where
+    F: Field<BasePrimeField = Fp<C, N>>,
     H: DuplexHash<Fp<C, N>>,
     R: RngCore + CryptoRng,
     C: FpConfig<N>,
 {
     type Repr = ();
 
-    fn public_scalars(&mut self, input: &[Fp<C, N>]) -> ProofResult<Self::Repr> {
-        self.public_units(input)?;
+    fn public_scalars(&mut self, input: &[F]) -> ProofResult<Self::Repr> {
+        self.public_units(&to_base_prime_field_units(input))?;
         Ok(())
     }
 }
@@ -126,15 +233,16 @@ where
 //
 //
 
-impl<H, C, const N: usize> FieldPublic<Fp<C, N>> for Arthur<'_, H, Fp<C, N>>
+impl<H, C, F, const N: usize> FieldPublic<F> for Arthur<'_, H, Fp<C, N>>
 where
+    F: Field<BasePrimeField = Fp<C, N>>,
     H: DuplexHash<Fp<C, N>>,
     C: FpConfig<N>,
 {
     type Repr = ();
 
-    fn public_scalars(&mut self, input: &[Fp<C, N>]) -> ProofResult<Self::Repr> {
-        self.public_units(input)?;
+    fn public_scalars(&mut self, input: &[F]) -> ProofResult<Self::Repr> {
+        self.public_units(&to_base_prime_field_units(input))?;
         Ok(())
     }
 }
@@ -144,14 +252,13 @@ where
     C: FpConfig<N>,
     R: RngCore + CryptoRng,
     H: DuplexHash<Fp<C, N>>,
-    G: CurveGroup<BaseField = Fp<C, N>>,
+    G: AlgebraicPoint<BaseField = Fp<C, N>>,
 {
     type Repr = ();
 
     fn public_points(&mut self, input: &[G]) -> ProofResult<Self::Repr> {
         for point in input {
-            let (x, y) = point.into_affine().xy().unwrap();
-            self.public_units(&[x, y])?;
+            self.public_units(&point.to_algebraic_units())?;
         }
         Ok(())
     }
@@ -161,21 +268,94 @@ impl<H, C, const N: usize, G> GroupPublic<G> for Arthur<'_, H, Fp<C, N>>
 where
     C: FpConfig<N>,
     H: DuplexHash<Fp<C, N>>,
-    G: CurveGroup<BaseField = Fp<C, N>>,
+    G: AlgebraicPoint<BaseField = Fp<C, N>>,
 {
     type Repr = ();
 
     fn public_points(&mut self, input: &[G]) -> ProofResult<Self::Repr> {
         for point in input {
-            let (x, y) = point.into_affine().xy().unwrap();
-            self.public_units(&[x, y])?;
+            self.public_units(&point.to_algebraic_units())?;
         }
         Ok(())
     }
 }
 
+// Non-native field elements:
+
+/// The number of limbs of a non-native element of `modulus_bits` bits, over a field of `native_bits` bits.
+pub(super) const fn non_native_limbs(modulus_bits: u32, native_bits: u32) -> usize {
+    (modulus_bits as usize).div_ceil(native_bits as usize - 1)
+}
+
+/// Decompose the canonical representative of `x` into little-endian limbs of `F::MODULUS_BIT_SIZE - 1` bits.
+pub(super) fn to_non_native_limbs<F2: PrimeField, F: PrimeField>(x: &F2) -> Vec<F> {
+    use ark_ff::BigInteger;
+
+    let bits = x.into_bigint().to_bits_le();
+    bits[..F2::MODULUS_BIT_SIZE as usize]
+        .chunks(F::MODULUS_BIT_SIZE as usize - 1)
+        .map(|limb| F::from_bigint(F::BigInt::from_bits_le(limb)).unwrap())
+        .collect()
+}
+
+/// Sample elements of `F2` from bytes, in a single call to [`ByteChallenges::fill_challenge_bytes`].
+fn fill_non_native_challenges<F2: PrimeField>(
+    transcript: &mut impl ByteChallenges,
+    output: &mut [F2],
+) -> ProofResult<()> {
+    let len = bytes_uniform_modp(F2::MODULUS_BIT_SIZE);
+    let mut buf = vec![0u8; output.len() * len];
+    transcript.fill_challenge_bytes(&mut buf)?;
+    for (o, chunk) in output.iter_mut().zip(buf.chunks(len)) {
+        *o = F2::from_be_bytes_mod_order(chunk);
+    }
+    Ok(())
+}
+
+impl<H, R, C, F2, const N: usize> NonNativeFieldChallenges<F2> for Merlin<H, Fp<C, N>, R>
+where
+    F2: PrimeField,
+    H: DuplexHash<Fp<C, N>>,
+    R: RngCore + CryptoRng,
+    C: FpConfig<N>,
+{
+    fn fill_challenge_non_native_scalars(&mut self, output: &mut [F2]) -> ProofResult<()> {
+        fill_non_native_challenges(self, output)
+    }
+}
+
+impl<H, C, F2, const N: usize> NonNativeFieldChallenges<F2> for Arthur<'_, H, Fp<C, N>>
+where
+    F2: PrimeField,
+    H: DuplexHash<Fp<C, N>>,
+    C: FpConfig<N>,
+{
+    fn fill_challenge_non_native_scalars(&mut self, output: &mut [F2]) -> ProofResult<()> {
+        fill_non_native_challenges(self, output)
+    }
+}
+
 // Field  <-> Bytes interactions:
 
+/// Fill `output` with bytes close to uniform, cf. [`uniform_bytes_from_units`].
+fn fill_challenge_bytes_native<C: FpConfig<N>, const N: usize>(
+    transcript: &mut impl UnitTranscript<Fp<C, N>>,
+    output: &mut [u8],
+    security_level: usize,
+) -> Result<(), IOPatternError> {
+    use ark_ff::BigInteger;
+
+    let k = units_for_challenge_bytes(output.len(), Fp::<C, N>::MODULUS_BIT_SIZE, security_level);
+    let mut units = vec![Fp::from(0); k];
+    transcript.fill_challenge_units(&mut units)?;
+    let units = units
+        .iter()
+        .map(|unit| unit.into_bigint().to_bytes_le())
+        .collect::<Vec<_>>();
+    uniform_bytes_from_units(&Fp::<C, N>::MODULUS.to_bytes_le(), &units, output);
+    Ok(())
+}
+
 impl<'a, H, C, const N: usize> BytePublic for Arthur<'a, H, Fp<C, N>>
 where
     C: FpConfig<N>,
@@ -210,18 +390,7 @@ where
     R: CryptoRng + rand::RngCore,
 {
     fn fill_challenge_bytes(&mut self, output: &mut [u8]) -> Result<(), IOPatternError> {
-        let len_good = usize::min(
-            crate::plugins::bytes_uniform_modp(Fp::<C, N>::MODULUS_BIT_SIZE),
-            output.len(),
-        );
-        let len = crate::plugins::bytes_modp(Fp::<C, N>::MODULUS_BIT_SIZE);
-        let mut tmp = [Fp::from(0); 1];
-        let mut buf = vec![0u8; len];
-        self.fill_challenge_units(&mut tmp)?;
-        tmp[0].serialize_compressed(&mut buf).unwrap();
-
-        output[..len_good].copy_from_slice(&buf[..len_good]);
-        Ok(())
+        fill_challenge_bytes_native(self, output, H::SECURITY_LEVEL)
     }
 }
 
@@ -231,17 +400,6 @@ where
     H: DuplexHash<Fp<C, N>>,
 {
     fn fill_challenge_bytes(&mut self, output: &mut [u8]) -> Result<(), IOPatternError> {
-        let len_good = usize::min(
-            crate::plugins::bytes_uniform_modp(Fp::<C, N>::MODULUS_BIT_SIZE),
-            output.len(),
-        );
-        let len = crate::plugins::bytes_modp(Fp::<C, N>::MODULUS_BIT_SIZE);
-        let mut tmp = [Fp::from(0); 1];
-        let mut buf = vec![0u8; len];
-        self.fill_challenge_units(&mut tmp)?;
-        tmp[0].serialize_compressed(&mut buf).unwrap();
-
-        output[..len_good].copy_from_slice(&buf[..len_good]);
-        Ok(())
+        fill_challenge_bytes_native(self, output, H::SECURITY_LEVEL)
     }
 }
@@ -0,0 +1,170 @@
+use ark_ec::short_weierstrass::{Affine as SWAffine, Projective as SWCurve, SWCurveConfig};
+use ark_ec::twisted_edwards::{Affine as EdwardsAffine, Projective as EdwardsCurve, TECurveConfig};
+use ark_ec::CurveGroup;
+use ark_ff::{Field, One, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+
+use crate::{PointEncoding, ProofError, ProofResult};
+
+/// Encoding and decoding of points of arkworks curves.
+impl PointEncoding {
+    /// The number of bytes used to encode a point of `G`.
+    pub fn size<G: CurveGroup>(self) -> usize {
+        match self {
+            Self::Compressed => G::default().compressed_size(),
+            Self::Uncompressed => G::default().uncompressed_size(),
+            Self::XOnly => G::BaseField::zero().compressed_size(),
+        }
+    }
+
+    /// Append the encoding of `point` to `buf`.
+    pub fn encode<G: EncodableCurve>(self, point: &G, buf: &mut Vec<u8>) -> ProofResult<()> {
+        match self {
+            Self::Compressed => point.serialize_compressed(buf)?,
+            Self::Uncompressed => point.serialize_uncompressed(buf)?,
+            Self::XOnly => point.x_only()?.serialize_compressed(buf)?,
+        }
+        Ok(())
+    }
+
+    /// Decode a point from `bytes`, checking that it lies in the prime-order subgroup.
+    pub fn decode<G: EncodableCurve>(self, bytes: &[u8]) -> ProofResult<G> {
+        Ok(match self {
+            Self::Compressed => G::deserialize_compressed(bytes)?,
+            Self::Uncompressed => G::deserialize_uncompressed(bytes)?,
+            Self::XOnly => G::from_x_only(G::BaseField::deserialize_compressed(bytes)?)?,
+        })
+    }
+}
+
+/// Curves whose points can be encoded with every [`PointEncoding`], including a single coordinate.
+///
+/// It is implemented for short Weierstrass and twisted Edwards curves,
+/// and required to write and read points in byte-oriented transcripts.
+pub trait EncodableCurve: CurveGroup {
+    /// The coordinate of the point, or an error for the identity.
+    fn x_only(&self) -> ProofResult<Self::BaseField>;
+
+    /// One of the two points of the prime-order subgroup with coordinate `x`.
+    fn from_x_only(x: Self::BaseField) -> ProofResult<Self>;
+}
+
+impl<P: SWCurveConfig> EncodableCurve for SWCurve<P> {
+    fn x_only(&self) -> ProofResult<P::BaseField> {
+        let affine = self.into_affine();
+        (!affine.infinity)
+            .then_some(affine.x)
+            .ok_or(ProofError::SerializationError)
+    }
+
+    fn from_x_only(x: P::BaseField) -> ProofResult<Self> {
+        SWAffine::<P>::get_point_from_x_unchecked(x, false)
+            .filter(|point| point.is_in_correct_subgroup_assuming_on_curve())
+            .map(Into::into)
+            .ok_or(ProofError::SerializationError)
+    }
+}
+
+/// The Montgomery $u$-coordinate is $u = (1 + y) / (1 - y)$.
+impl<P: TECurveConfig> EncodableCurve for EdwardsCurve<P> {
+    fn x_only(&self) -> ProofResult<P::BaseField> {
+        let affine = self.into_affine();
+        let one = P::BaseField::one();
+        (one - affine.y)
+            .inverse()
+            .map(|inv| (one + affine.y) * inv)
+            .ok_or(ProofError::SerializationError)
+    }
+
+    fn from_x_only(u: P::BaseField) -> ProofResult<Self> {
+        let one = P::BaseField::one();
+        let y = (u + one)
+            .inverse()
+            .map(|inv| (u - one) * inv)
+            .ok_or(ProofError::SerializationError)?;
+        EdwardsAffine::<P>::get_point_from_y_unchecked(y, false)
+            .filter(|point| !point.is_zero() && point.is_in_correct_subgroup_assuming_on_curve())
+            .map(Into::into)
+            .ok_or(ProofError::SerializationError)
+    }
+}
+
+/// The number of field elements absorbed per point by algebraic sponges, cf. [`AlgebraicPoint`].
+pub(super) const POINT_UNITS: usize = 3;
+
+/// The encoding of points absorbed by sponges over the base field of the curve.
+///
+/// A point is absorbed as $(\mathsf{tag}, x, y)$, where $(x, y)$ are its affine coordinates,
+/// or $(0, 0)$ for the point at infinity of short Weierstrass curves.
+/// The tag is $2m + i$, where $m$ identifies the curve model (1 for short Weierstrass, 2 for twisted Edwards)
+/// and $i$ is 1 for the identity and 0 otherwise.
+/// The encoding is thus injective.
+///
+/// The curve itself is bound once in the IO pattern, where the labels of points are prefixed with
+/// [`AlgebraicPoint::MODEL_NAME`] and the coefficients, e.g. `sw(0,4):` for BLS12-381 $\mathbb{G}_1$.
+/// The coefficients $(c_0, c_1)$ identify the curve within its model, $(a, b)$ for $y^2 = x^3 + ax + b$,
+/// and $(a, d)$ for $ax^2 + y^2 = 1 + dx^2y^2$.
+/// Points of different curves over the same field are thus never absorbed by the same IO pattern.
+pub trait AlgebraicPoint: CurveGroup {
+    /// The identifier of the curve model.
+    const MODEL: u64;
+
+    /// The short name of the curve model, used in the IO pattern.
+    const MODEL_NAME: &'static str;
+
+    /// The coefficients of the curve equation, identifying the curve within its model.
+    const COEFFICIENTS: [Self::BaseField; 2];
+
+    /// The field elements absorbed for the point.
+    fn to_algebraic_units(&self) -> [Self::BaseField; POINT_UNITS];
+}
+
+/// The curve model and coefficients, prepended to the labels of points in IO patterns over the base field,
+/// e.g. `sw(0,4):` for BLS12-381 $\mathbb{G}_1$.
+pub(super) fn curve_label<G>() -> String
+where
+    G: AlgebraicPoint,
+    G::BaseField: PrimeField,
+{
+    let [c0, c1] = G::COEFFICIENTS;
+    format!(
+        "{}({},{}):",
+        G::MODEL_NAME,
+        c0.into_bigint(),
+        c1.into_bigint()
+    )
+}
+
+/// The tag absorbed before the coordinates of a point.
+fn algebraic_tag<G: AlgebraicPoint>(is_identity: bool) -> G::BaseField {
+    G::BaseField::from_base_prime_field(<G::BaseField as Field>::BasePrimeField::from(
+        2 * G::MODEL + is_identity as u64,
+    ))
+}
+
+impl<P: SWCurveConfig> AlgebraicPoint for SWCurve<P> {
+    const MODEL: u64 = 1;
+    const MODEL_NAME: &'static str = "sw";
+    const COEFFICIENTS: [P::BaseField; 2] = [P::COEFF_A, P::COEFF_B];
+
+    fn to_algebraic_units(&self) -> [P::BaseField; POINT_UNITS] {
+        let affine = self.into_affine();
+        if affine.infinity {
+            let zero = P::BaseField::zero();
+            [algebraic_tag::<Self>(true), zero, zero]
+        } else {
+            [algebraic_tag::<Self>(false), affine.x, affine.y]
+        }
+    }
+}
+
+impl<P: TECurveConfig> AlgebraicPoint for EdwardsCurve<P> {
+    const MODEL: u64 = 2;
+    const MODEL_NAME: &'static str = "te";
+    const COEFFICIENTS: [P::BaseField; 2] = [P::COEFF_A, P::COEFF_D];
+
+    fn to_algebraic_units(&self) -> [P::BaseField; POINT_UNITS] {
+        let affine = self.into_affine();
+        [algebraic_tag::<Self>(affine.is_zero()), affine.x, affine.y]
+    }
+}
@@ -0,0 +1,78 @@
+//! Generated with `scripts/griffin_constants.py`.
+use ark_bls12_381::Fr;
+use ark_ff::MontFp;
+
+pub type Field = Fr;
+pub const D: u64 = 5;
+pub const D_INV: &[u64] = &[
+    3689348813023923405,
+    2413663763415232921,
+    16233882818423549954,
+    3341406743785779740,
+];
+pub const ROUNDS: usize = 12;
+pub const N: usize = 3;
+pub const R: usize = 2;
+
+pub const ALPHA: Fr =
+    MontFp!("45625162168789527948960254146378832463448187293588773809898313480610702050228");
+pub const BETA: Fr =
+    MontFp!("29596883763622028957399290011686609329190545253889979909788972168607161253020");
+
+pub const ROUND_CONSTANTS: &[[Fr; 3]] = &[
+    [
+        MontFp!("40437894905802310551161824324860525912776996054350446973274840658262746059250"),
+        MontFp!("30280431043134759299416213470060062199178349055755145064764392985086389956009"),
+        MontFp!("32111520244035483628984798045720362355613498846674003845356617895104502726768"),
+    ],
+    [
+        MontFp!("12401939489697081322881468886555449389271986316875478962489170046944490678211"),
+        MontFp!("39046290751497162845719214446654689447897008303733943875796338866830548924523"),
+        MontFp!("28608377240931620553310271232580708065499397416709117663009356462893224047450"),
+    ],
+    [
+        MontFp!("22646786431192560303727568340101597297862457407062603673554642624600193260778"),
+        MontFp!("29103301615046792221243115201101355091413804995088674377357492684627757798993"),
+        MontFp!("8096951885261860742182380605497784223703713159222917132971170471321518872323"),
+    ],
+    [
+        MontFp!("4423441849673172207830382498850752889038086568410783464678119231438191743566"),
+        MontFp!("40023178315815584436208854236012123788969890347659766714009481211826955119208"),
+        MontFp!("30199115252840266872992515578209071898127099601600928608377355576031592071978"),
+    ],
+    [
+        MontFp!("25743103039526144386702706843388523796849140630292154928222447837529843018616"),
+        MontFp!("34113289223469530777834365155543217754534341286418726212419261393907143279138"),
+        MontFp!("48496322286779062409711673156736032145880448442991268836671905368408410383352"),
+    ],
+    [
+        MontFp!("13249545148771584554078300052950833779983825872288134846949998816331810173449"),
+        MontFp!("46175264717818089149339984871811151810334377044352716137791758891152648886564"),
+        MontFp!("16937396465431377017200642359291704473540658222088081922782212142901902635722"),
+    ],
+    [
+        MontFp!("19790876940256368721650395241300990030216328490993693580837358357274815816699"),
+        MontFp!("26248103966003492928684334671926817879032355936631594871722121969576534731621"),
+        MontFp!("23135631893005892261082630500925311201521790459812947934147694811782844218012"),
+    ],
+    [
+        MontFp!("34793087247570783754240239238352723296594411185322201780856637515961874111643"),
+        MontFp!("17326484155835074861089851096836204647666496488438331393890255200360207684363"),
+        MontFp!("1974711749081269789214816658107864456104193450769434289509435451147223804709"),
+    ],
+    [
+        MontFp!("13463894059429208070121797077860139789707810933526198130940195831466558118229"),
+        MontFp!("32002802579185385056351951570800688296555241670547659835727616788080906812529"),
+        MontFp!("52115308293561609539689471693574790567220830513976173936329784768315984529776"),
+    ],
+    [
+        MontFp!("49405656385101179096645767796661428296281204165794538830113532887146062633988"),
+        MontFp!("5422036728972123374538739383296954856040249670753903950427059378639298709754"),
+        MontFp!("7540956356339881032879221798751993041192902140006782025139977810000787076187"),
+    ],
+    [
+        MontFp!("48659579856861542978688217011974423197400785214670661991086632460716256322155"),
+        MontFp!("50696474931165553585933275287497094839007687200656585977022153840606943406207"),
+        MontFp!("49548649157282158011791506070151270907007016291954144331339504848201789343306"),
+    ],
+];
@@ -0,0 +1,5 @@
+use crate::plugins::ark::griffin;
+
+mod fr_3_2;
+
+griffin_sponge!(GriffinBls12381Fr3_2, fr_3_2);
@@ -0,0 +1,180 @@
+//! The [Griffin](https://eprint.iacr.org/2022/403) permutation.
+//!
+//! After an initial linear layer, each round applies the non-linear layer,
+//! the linear layer, and adds the round constants (except for the last round).
+use ark_ff::PrimeField;
+
+use crate::hash::sponge::{algebraic_security_level, Sponge};
+use crate::hash::Unit;
+
+#[derive(Clone)]
+pub struct GriffinState<F: PrimeField, const R: usize, const N: usize> {
+    /// Number of rounds.
+    pub rounds: usize,
+    /// Exponent used in the S-box of the second state element.
+    pub d: u64,
+    /// Exponent used in the S-box of the first state element, i.e. $d^{-1} \bmod (p-1)$, as little-endian 64-bit limbs.
+    pub d_inv: &'static [u64],
+    /// Coefficients $(\alpha, \beta)$ of the quadratic functions used from the third state element onwards.
+    /// The $i$-th element of the state uses $((i-1) \alpha, (i-1)^2 \beta)$.
+    pub alpha_beta: [F; 2],
+    /// Round constants, added at the end of every round but the last one.
+    /// They are indexed by `round_constants[round_num][state_element_index]`
+    pub round_constants: &'static [[F; N]],
+
+    /// Sponge state
+    pub state: [F; N],
+}
+
+pub type GriffinHash<F, const R: usize, const N: usize> =
+    crate::hash::sponge::DuplexSponge<GriffinState<F, R, N>>;
+
+impl<F: PrimeField, const R: usize, const N: usize> AsRef<[F]> for GriffinState<F, R, N> {
+    fn as_ref(&self) -> &[F] {
+        &self.state
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> AsMut<[F]> for GriffinState<F, R, N> {
+    fn as_mut(&mut self) -> &mut [F] {
+        &mut self.state
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> GriffinState<F, R, N> {
+    fn apply_non_linear(&self, state: &mut [F; N]) {
+        // L_i depends on the input x_{i-1}, not on the output y_{i-1}.
+        let x = *state;
+        state[0] = x[0].pow(self.d_inv);
+        state[1] = x[1].pow([self.d]);
+
+        let y0 = state[0];
+        // y0 + y1, to which y0 is added once more for every following state element.
+        let mut y01_i = state[0] + state[1];
+        for i in 2..N {
+            let l = if i == 2 {
+                y01_i
+            } else {
+                y01_i += y0;
+                y01_i + x[i - 1]
+            };
+            let [alpha, beta] = self.alpha_beta;
+            let scale = F::from((i - 1) as u64);
+            state[i] *= l.square() + alpha * scale * l + beta * scale.square();
+        }
+    }
+
+    fn apply_linear(state: &mut [F; N]) {
+        match N {
+            // circulant matrix circ(2, 1, 1)
+            3 => {
+                let sum = state[0] + state[1] + state[2];
+                state.iter_mut().for_each(|x| *x += sum);
+            }
+            4 => Self::apply_m4(state),
+            // block matrix circ(2 M4, M4, ..., M4)
+            _ => {
+                for chunk in state.chunks_exact_mut(4) {
+                    Self::apply_m4(chunk);
+                }
+                let mut sums = [F::zero(); 4];
+                for chunk in state.chunks_exact(4) {
+                    sums.iter_mut().zip(chunk).for_each(|(s, x)| *s += x);
+                }
+                for chunk in state.chunks_exact_mut(4) {
+                    chunk.iter_mut().zip(&sums).for_each(|(x, s)| *x += s);
+                }
+            }
+        }
+    }
+
+    /// Multiply a chunk of four elements by the matrix
+    /// `[[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]`.
+    fn apply_m4(x: &mut [F]) {
+        let t0 = x[0] + x[1];
+        let t1 = x[2] + x[3];
+        let t2 = x[1].double() + t1;
+        let t3 = x[3].double() + t0;
+        let t4 = t1.double().double() + t3;
+        let t5 = t0.double().double() + t2;
+        let t6 = t3 + t5;
+        let t7 = t2 + t4;
+        x.copy_from_slice(&[t6, t5, t7, t4]);
+    }
+
+    fn apply_round_constants(state: &mut [F; N], constants: &[F; N]) {
+        for (state_elem, c) in state.iter_mut().zip(constants) {
+            state_elem.add_assign(c);
+        }
+    }
+
+    pub(crate) fn apply_permutation(&mut self) {
+        let mut state = self.state;
+        Self::apply_linear(&mut state);
+        for round in 0..self.rounds {
+            self.apply_non_linear(&mut state);
+            Self::apply_linear(&mut state);
+            if round < self.rounds - 1 {
+                Self::apply_round_constants(&mut state, &self.round_constants[round]);
+            }
+        }
+        self.state = state;
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> zeroize::Zeroize for GriffinState<F, R, N> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl<F, const R: usize, const N: usize> Sponge for GriffinState<F, R, N>
+where
+    GriffinState<F, R, N>: Default,
+    F: PrimeField + Unit,
+{
+    type U = F;
+    const N: usize = N;
+    const R: usize = R;
+    const SECURITY_LEVEL: usize = algebraic_security_level(N - R, F::MODULUS_BIT_SIZE);
+
+    fn new(iv: [u8; 32]) -> Self {
+        assert!(
+            N == 3 || (N >= 4 && N.is_multiple_of(4)),
+            "Griffin is defined for width 3 or a multiple of 4."
+        );
+        let mut sponge = Self::default();
+        sponge.state[R] = F::from_be_bytes_mod_order(&iv);
+        sponge
+    }
+
+    fn permute(&mut self) {
+        self.apply_permutation();
+    }
+}
+
+/// Initialization of constants.
+#[allow(unused)]
+macro_rules! griffin_sponge {
+    ($name: ident, $path: tt) => {
+        pub type $name = crate::hash::sponge::DuplexSponge<
+            griffin::GriffinState<$path::Field, { $path::R }, { $path::N }>,
+        >;
+
+        impl Default for griffin::GriffinState<$path::Field, { $path::R }, { $path::N }> {
+            fn default() -> Self {
+                Self {
+                    rounds: $path::ROUNDS,
+                    d: $path::D,
+                    d_inv: $path::D_INV,
+                    alpha_beta: [$path::ALPHA, $path::BETA],
+                    round_constants: $path::ROUND_CONSTANTS,
+                    state: [ark_ff::Zero::zero(); $path::N],
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "ark-bls12-381")]
+pub mod bls12_381;
@@ -0,0 +1,498 @@
+//! A [`halo2_proofs`] chip for transcripts over Poseidon.
+//!
+//! [`SafeChip`][`crate::plugins::ark::halo2::SafeChip`] constrains, inside a plonkish circuit, the same operations that [`Safe`] performs natively:
+//! the initialization of the sponge with the tag of the IO Pattern (as in [`Safe::new`]),
+//! and the absorb, squeeze, and ratchet operations of [`DuplexSponge`][`crate::hash::sponge::DuplexSponge`] in overwrite mode.
+//!
+//! The circuit is defined over a [`FieldExt`][`halo2_proofs::arithmetic::FieldExt`] (e.g. the Pasta fields),
+//! while the native transcript can be over an arkworks field, with [`PoseidonHash`][`crate::plugins::ark::poseidon::PoseidonHash`],
+//! or over the [`FieldUnit`][`crate::plugins::group::FieldUnit`]s of the `group` plugin,
+//! with [`PoseidonHash`][`crate::plugins::group::poseidon::PoseidonHash`] (e.g. with the Orchard parameters of [`crate::plugins::group::poseidon::pasta`]).
+//! The parameters of the native sponge are moved to the circuit with [`PoseidonParameters`][`crate::plugins::ark::halo2::PoseidonParameters`].
+//! The fields must have the same modulus.
+use std::collections::VecDeque;
+
+use ark_ff::{BigInteger, PrimeField};
+use halo2_proofs::arithmetic::FieldExt;
+use halo2_proofs::circuit::{AssignedCell, Chip, Layouter};
+use halo2_proofs::plonk::{
+    Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, VirtualCells,
+};
+use halo2_proofs::poly::Rotation;
+
+use super::poseidon::PoseidonSponge;
+use crate::hash::Unit;
+use crate::iopattern::{pop_op, Op};
+use crate::{DuplexHash, IOPattern, Safe};
+
+/// Move an element of the arkworks field `A` to the circuit field `F`.
+///
+/// # Panics
+///
+/// If the element does not fit in `F`.
+fn to_circuit_field<A: PrimeField, F: FieldExt>(a: A) -> F {
+    let bytes = a.into_bigint().to_bytes_le();
+    let mut repr = F::Repr::default();
+    let len = usize::min(bytes.len(), repr.as_ref().len());
+    assert!(bytes[len..].iter().all(|&b| b == 0));
+    repr.as_mut()[..len].copy_from_slice(&bytes[..len]);
+    Option::from(F::from_repr(repr)).expect("Element does not fit in the circuit field")
+}
+
+/// Move an element of the `group` field `G` to the circuit field `F`.
+///
+/// # Panics
+///
+/// If the element does not fit in `F`, or if the representations of `G` are not little-endian.
+#[cfg(feature = "group")]
+fn group_to_circuit_field<G: group::ff::PrimeField, F: FieldExt>(g: G) -> F {
+    let bytes = g.to_repr();
+    let mut repr = F::Repr::default();
+    assert_eq!(bytes.as_ref().len(), repr.as_ref().len());
+    repr.as_mut().copy_from_slice(bytes.as_ref());
+    Option::from(F::from_repr(repr)).expect("Element does not fit in the circuit field")
+}
+
+/// The parameters of the Poseidon permutation, over the circuit field `F`.
+///
+/// They are obtained from the parameters of a native sponge over a field with the same modulus.
+#[derive(Clone, Debug)]
+pub struct PoseidonParameters<F: FieldExt, const N: usize> {
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    pub alpha: u64,
+    pub ark: Vec<[F; N]>,
+    pub mds: Vec<[F; N]>,
+}
+
+/// # Panics
+///
+/// If the modulus of `A` differs from the one of `F`.
+impl<A: PrimeField, F: FieldExt, const R: usize, const N: usize> From<&PoseidonSponge<A, R, N>>
+    for PoseidonParameters<F, N>
+{
+    fn from(sponge: &PoseidonSponge<A, R, N>) -> Self {
+        assert_eq!(
+            to_circuit_field::<A, F>(-A::one()),
+            -F::one(),
+            "The circuit field and the field of the sponge must have the same modulus"
+        );
+        Self {
+            full_rounds: sponge.full_rounds,
+            partial_rounds: sponge.partial_rounds,
+            alpha: sponge.alpha,
+            ark: sponge
+                .ark
+                .iter()
+                .map(|row| row.map(to_circuit_field))
+                .collect(),
+            mds: sponge
+                .mds
+                .iter()
+                .map(|row| row.map(to_circuit_field))
+                .collect(),
+        }
+    }
+}
+
+/// # Panics
+///
+/// If the modulus of `G` differs from the one of `F`.
+#[cfg(feature = "group")]
+impl<G, F, const R: usize, const N: usize>
+    From<&crate::plugins::group::poseidon::PoseidonSponge<G, R, N>> for PoseidonParameters<F, N>
+where
+    G: group::ff::PrimeField,
+    F: FieldExt,
+{
+    fn from(sponge: &crate::plugins::group::poseidon::PoseidonSponge<G, R, N>) -> Self {
+        assert!(
+            group_to_circuit_field::<G, F>(-G::ONE) == -F::one()
+                && group_to_circuit_field::<G, F>(G::from(2)) == F::from(2),
+            "The circuit field and the field of the sponge must have the same modulus"
+        );
+        Self {
+            full_rounds: sponge.full_rounds,
+            partial_rounds: sponge.partial_rounds,
+            alpha: sponge.alpha,
+            ark: sponge
+                .ark
+                .iter()
+                .map(|row| row.map(group_to_circuit_field))
+                .collect(),
+            mds: sponge
+                .mds
+                .iter()
+                .map(|row| row.map(group_to_circuit_field))
+                .collect(),
+        }
+    }
+}
+
+/// The configuration of [`PoseidonChip`].
+///
+/// Each round of the permutation takes a row:
+/// the state before the round is in `state`, and the round constants in `round_constants`.
+/// The output of the permutation is in the row following the last round.
+#[derive(Clone, Debug)]
+pub struct PoseidonConfig<F: FieldExt, const N: usize> {
+    state: [Column<Advice>; N],
+    round_constants: [Column<Fixed>; N],
+    s_full: Selector,
+    s_partial: Selector,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+    ark: Vec<[F; N]>,
+    mds: Vec<[F; N]>,
+}
+
+impl<F: FieldExt, const N: usize> PoseidonConfig<F, N> {
+    fn is_full_round(&self, round: usize) -> bool {
+        let full_rounds_over_2 = self.full_rounds / 2;
+        round < full_rounds_over_2 || round >= full_rounds_over_2 + self.partial_rounds
+    }
+
+    /// Compute natively the state after the given round.
+    fn round(&self, state: &[F], round: usize) -> Vec<F> {
+        let sboxes = if self.is_full_round(round) { N } else { 1 };
+        let state = state
+            .iter()
+            .zip(&self.ark[round])
+            .enumerate()
+            .map(|(i, (x, c))| {
+                let x = *x + c;
+                if i < sboxes {
+                    x.pow_vartime([self.alpha])
+                } else {
+                    x
+                }
+            })
+            .collect::<Vec<_>>();
+        self.mds
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&state)
+                    .fold(F::zero(), |acc, (m, x)| acc + *m * x)
+            })
+            .collect()
+    }
+}
+
+/// A chip for the Poseidon permutation, as in [`PoseidonSponge`].
+pub struct PoseidonChip<F: FieldExt, const R: usize, const N: usize> {
+    config: PoseidonConfig<F, N>,
+}
+
+impl<F: FieldExt, const R: usize, const N: usize> Chip<F> for PoseidonChip<F, R, N> {
+    type Config = PoseidonConfig<F, N>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt, const R: usize, const N: usize> PoseidonChip<F, R, N> {
+    pub fn construct(config: PoseidonConfig<F, N>) -> Self {
+        Self { config }
+    }
+
+    /// Configure the chip with the parameters of the native sponge.
+    ///
+    /// `constants` is used to assign the initial state of the sponge.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        state: [Column<Advice>; N],
+        round_constants: [Column<Fixed>; N],
+        constants: Column<Fixed>,
+        parameters: PoseidonParameters<F, N>,
+    ) -> PoseidonConfig<F, N> {
+        for column in state {
+            meta.enable_equality(column);
+        }
+        meta.enable_constant(constants);
+
+        let config = PoseidonConfig {
+            state,
+            round_constants,
+            s_full: meta.selector(),
+            s_partial: meta.selector(),
+            full_rounds: parameters.full_rounds,
+            partial_rounds: parameters.partial_rounds,
+            alpha: parameters.alpha,
+            ark: parameters.ark,
+            mds: parameters.mds,
+        };
+
+        let round_gate = |meta: &mut VirtualCells<'_, F>, selector: Selector, sboxes: usize| {
+            let selector = meta.query_selector(selector);
+            let state = (0..N)
+                .map(|i| {
+                    let x = meta.query_advice(config.state[i], Rotation::cur())
+                        + meta.query_fixed(config.round_constants[i], Rotation::cur());
+                    if i < sboxes {
+                        (1..config.alpha).fold(x.clone(), |acc, _| acc * x.clone())
+                    } else {
+                        x
+                    }
+                })
+                .collect::<Vec<_>>();
+            (0..N)
+                .map(|i| {
+                    let next = meta.query_advice(config.state[i], Rotation::next());
+                    let mds = config.mds[i]
+                        .iter()
+                        .zip(&state)
+                        .fold(Expression::Constant(F::zero()), |acc, (m, x)| {
+                            acc + x.clone() * Expression::Constant(*m)
+                        });
+                    selector.clone() * (mds - next)
+                })
+                .collect::<Vec<_>>()
+        };
+        meta.create_gate("poseidon full round", |meta| {
+            round_gate(meta, config.s_full, N)
+        });
+        meta.create_gate("poseidon partial round", |meta| {
+            round_gate(meta, config.s_partial, 1)
+        });
+        config
+    }
+
+    /// Assign a state made of constants.
+    fn assign_constants(
+        &self,
+        mut layouter: impl Layouter<F>,
+        constants: &[F],
+    ) -> Result<Vec<AssignedCell<F, F>>, Error> {
+        layouter.assign_region(
+            || "constants",
+            |mut region| {
+                constants
+                    .iter()
+                    .zip(self.config.state)
+                    .map(|(c, column)| {
+                        region.assign_advice_from_constant(|| "constant", column, 0, *c)
+                    })
+                    .collect()
+            },
+        )
+    }
+
+    /// Apply the permutation to `state`.
+    pub fn permute(
+        &self,
+        mut layouter: impl Layouter<F>,
+        state: &[AssignedCell<F, F>],
+    ) -> Result<Vec<AssignedCell<F, F>>, Error> {
+        let config = &self.config;
+        layouter.assign_region(
+            || "poseidon permutation",
+            |mut region| {
+                let mut cells = state
+                    .iter()
+                    .zip(config.state)
+                    .map(|(cell, column)| cell.copy_advice(|| "input", &mut region, column, 0))
+                    .collect::<Result<Vec<_>, _>>()?;
+                for round in 0..config.full_rounds + config.partial_rounds {
+                    if config.is_full_round(round) {
+                        config.s_full.enable(&mut region, round)?;
+                    } else {
+                        config.s_partial.enable(&mut region, round)?;
+                    }
+                    for (column, c) in config.round_constants.iter().zip(config.ark[round]) {
+                        region.assign_fixed(|| "round constant", *column, round, || Ok(c))?;
+                    }
+                    let next = cells
+                        .iter()
+                        .map(|cell| cell.value().copied())
+                        .collect::<Option<Vec<_>>>()
+                        .map(|state| config.round(&state, round));
+                    cells = config
+                        .state
+                        .iter()
+                        .enumerate()
+                        .map(|(i, column)| {
+                            region.assign_advice(
+                                || "state",
+                                *column,
+                                round + 1,
+                                || next.as_ref().map(|state| state[i]).ok_or(Error::Synthesis),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()?;
+                }
+                Ok(cells)
+            },
+        )
+    }
+}
+
+/// The in-circuit counterpart of [`PoseidonHash`][`crate::plugins::ark::poseidon::PoseidonHash`]:
+/// a duplex sponge in overwrite mode over [`PoseidonChip`].
+pub struct PoseidonSpongeChip<F: FieldExt, const R: usize, const N: usize> {
+    chip: PoseidonChip<F, R, N>,
+    state: Vec<AssignedCell<F, F>>,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+}
+
+impl<F: FieldExt, const R: usize, const N: usize> PoseidonSpongeChip<F, R, N> {
+    /// Initialize the sponge, writing the IV in the capacity as [`PoseidonSponge`] does.
+    pub fn new(
+        chip: PoseidonChip<F, R, N>,
+        layouter: impl Layouter<F>,
+        iv: F,
+    ) -> Result<Self, Error> {
+        assert!(N > R, "Capacity of the sponge should be > 0.");
+        let mut initial_state = [F::zero(); N];
+        initial_state[R] = iv;
+        let state = chip.assign_constants(layouter, &initial_state)?;
+        Ok(Self {
+            chip,
+            state,
+            absorb_pos: 0,
+            squeeze_pos: R,
+        })
+    }
+
+    fn permute(&mut self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        self.state = self
+            .chip
+            .permute(layouter.namespace(|| "permute"), &self.state)?;
+        Ok(())
+    }
+
+    /// Absorb `input` into the sponge.
+    pub fn absorb(
+        &mut self,
+        mut layouter: impl Layouter<F>,
+        mut input: &[AssignedCell<F, F>],
+    ) -> Result<(), Error> {
+        while !input.is_empty() {
+            if self.absorb_pos == R {
+                self.permute(&mut layouter)?;
+                self.absorb_pos = 0;
+            } else {
+                let chunk_len = usize::min(input.len(), R - self.absorb_pos);
+                let (chunk, rest) = input.split_at(chunk_len);
+                self.state[self.absorb_pos..self.absorb_pos + chunk_len].clone_from_slice(chunk);
+                self.absorb_pos += chunk_len;
+                input = rest;
+            }
+        }
+        self.squeeze_pos = R;
+        Ok(())
+    }
+
+    /// Squeeze `count` elements from the sponge.
+    pub fn squeeze(
+        &mut self,
+        mut layouter: impl Layouter<F>,
+        count: usize,
+    ) -> Result<Vec<AssignedCell<F, F>>, Error> {
+        let mut output = Vec::with_capacity(count);
+        while output.len() < count {
+            if self.squeeze_pos == R {
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+                self.permute(&mut layouter)?;
+            }
+            let chunk_len = usize::min(count - output.len(), R - self.squeeze_pos);
+            output.extend_from_slice(&self.state[self.squeeze_pos..self.squeeze_pos + chunk_len]);
+            self.squeeze_pos += chunk_len;
+        }
+        Ok(output)
+    }
+
+    /// Permute the state and zero the rate.
+    pub fn ratchet(&mut self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        self.permute(&mut layouter)?;
+        let zeros = self
+            .chip
+            .assign_constants(layouter.namespace(|| "zero rate"), &[F::zero(); R])?;
+        self.state[..R].clone_from_slice(&zeros);
+        self.squeeze_pos = R;
+        Ok(())
+    }
+}
+
+/// The in-circuit counterpart of [`Safe`] over Poseidon.
+///
+/// Any deviation from the IO Pattern results in [`Error::Synthesis`].
+pub struct SafeChip<F: FieldExt, const R: usize, const N: usize> {
+    sponge: PoseidonSpongeChip<F, R, N>,
+    stack: VecDeque<Op>,
+}
+
+impl<F: FieldExt, const R: usize, const N: usize> SafeChip<F, R, N> {
+    /// Initialize the sponge with the tag of `io_pattern`, as [`Safe::new`] does.
+    ///
+    /// The native hash `H` must be a Poseidon sponge with the parameters of `chip`,
+    /// which writes the tag in the capacity as an integer modulo $p$, as both
+    /// [`PoseidonSponge`] and [`crate::plugins::group::poseidon::PoseidonSponge`] do.
+    pub fn new<H, U>(
+        chip: PoseidonChip<F, R, N>,
+        layouter: impl Layouter<F>,
+        io_pattern: &IOPattern<H, U>,
+    ) -> Result<Self, Error>
+    where
+        U: Unit,
+        H: DuplexHash<U>,
+    {
+        let tag = Safe::<H, U>::generate_tag(io_pattern.as_bytes());
+        let iv = tag
+            .iter()
+            .fold(F::zero(), |acc, &b| acc * F::from(256) + F::from(b as u64));
+        Ok(Self {
+            sponge: PoseidonSpongeChip::new(chip, layouter, iv)?,
+            stack: io_pattern.finalize(),
+        })
+    }
+
+    /// Check that the next operation is `expected`, cf. [`pop_op`].
+    fn pop_op(&mut self, expected: Op) -> Result<(), Error> {
+        pop_op(&mut self.stack, expected).map_err(|e| {
+            log::error!("{}", e);
+            Error::Synthesis
+        })
+    }
+
+    /// Absorb `input`.
+    pub fn absorb(
+        &mut self,
+        layouter: impl Layouter<F>,
+        input: &[AssignedCell<F, F>],
+    ) -> Result<(), Error> {
+        if input.is_empty() {
+            return Ok(());
+        }
+        self.pop_op(Op::Absorb(input.len()))?;
+        self.sponge.absorb(layouter, input)
+    }
+
+    /// Squeeze `count` challenges.
+    pub fn squeeze(
+        &mut self,
+        layouter: impl Layouter<F>,
+        count: usize,
+    ) -> Result<Vec<AssignedCell<F, F>>, Error> {
+        if count == 0 {
+            return Ok(Vec::new());
+        }
+        self.pop_op(Op::Squeeze(count))?;
+        self.sponge.squeeze(layouter, count)
+    }
+
+    /// Ratchet the sponge.
+    pub fn ratchet(&mut self, layouter: impl Layouter<F>) -> Result<(), Error> {
+        self.pop_op(Op::Ratchet)?;
+        self.sponge.ratchet(layouter)
+    }
+}
@@ -1,34 +1,59 @@
+use ark_ec::pairing::{Pairing, PairingOutput};
 use ark_ec::CurveGroup;
-use ark_ff::{Fp, FpConfig, PrimeField};
+use ark_ff::{Field, Fp, FpConfig, PrimeField};
+use ark_serialize::CanonicalSerialize;
 
 use super::*;
-use crate::plugins::{bytes_modp, bytes_uniform_modp};
+use crate::plugins::{
+    bytes_modp, bytes_short_scalar, bytes_uniform_modp, units_for_challenge_bytes,
+};
 
 impl<F, H> FieldIOPattern<F> for IOPattern<H>
 where
-    F: PrimeField,
+    F: Field,
     H: DuplexHash,
 {
     fn add_scalars(self, count: usize, label: &str) -> Self {
-        self.add_bytes(count * bytes_modp(F::MODULUS_BIT_SIZE), label)
+        let degree = F::extension_degree() as usize;
+        self.add_bytes(
+            count * degree * bytes_modp(F::BasePrimeField::MODULUS_BIT_SIZE),
+            label,
+        )
     }
 
     fn challenge_scalars(self, count: usize, label: &str) -> Self {
-        self.challenge_bytes(count * bytes_uniform_modp(F::MODULUS_BIT_SIZE), label)
+        let degree = F::extension_degree() as usize;
+        self.challenge_bytes(
+            count * degree * bytes_uniform_modp(F::BasePrimeField::MODULUS_BIT_SIZE),
+            label,
+        )
+    }
+
+    fn challenge_short_scalars(self, count: usize, bits: usize, label: &str) -> Self {
+        self.challenge_bytes(count * bytes_short_scalar(bits), label)
     }
 }
 
-impl<C, H, const N: usize> FieldIOPattern<Fp<C, N>> for IOPattern<H, Fp<C, N>>
+impl<C, H, F, const N: usize> FieldIOPattern<F> for IOPattern<H, Fp<C, N>>
 where
+    F: Field<BasePrimeField = Fp<C, N>>,
     C: FpConfig<N>,
     H: DuplexHash<Fp<C, N>>,
 {
     fn add_scalars(self, count: usize, label: &str) -> Self {
-        self.absorb(count, label)
+        self.absorb(count * F::extension_degree() as usize, label)
     }
 
+    /// Each coefficient over the base prime field is sampled from bytes, cf. [`ByteIOPattern::challenge_bytes`].
     fn challenge_scalars(self, count: usize, label: &str) -> Self {
-        self.squeeze(count, label)
+        let bits = Fp::<C, N>::MODULUS_BIT_SIZE;
+        let units = units_for_challenge_bytes(bytes_uniform_modp(bits), bits, H::SECURITY_LEVEL);
+        self.squeeze(count * F::extension_degree() as usize * units, label)
+    }
+
+    /// Short scalars are squeezed as bytes, cf. [`ByteIOPattern::challenge_bytes`].
+    fn challenge_short_scalars(self, count: usize, bits: usize, label: &str) -> Self {
+        self.challenge_bytes(count * bytes_short_scalar(bits), label)
     }
 }
 
@@ -42,8 +67,10 @@ where
     }
 
     fn challenge_bytes(self, count: usize, label: &str) -> Self {
-        let n = bytes_uniform_modp(Fp::<C, N>::MODULUS_BIT_SIZE);
-        self.squeeze((count + n - 1) / n, label)
+        self.squeeze(
+            units_for_challenge_bytes(count, Fp::<C, N>::MODULUS_BIT_SIZE, H::SECURITY_LEVEL),
+            label,
+        )
     }
 }
 
@@ -52,20 +79,69 @@ where
     G: CurveGroup,
     H: DuplexHash,
 {
+    /// Points are sized with the [`PointEncoding`] of the IO Pattern.
+    /// Except for the default compressed encoding, the label is prefixed with the [`PointEncoding::name`],
+    /// so that encodings of the same size, e.g. compressed and x-only points, give different IO patterns.
     fn add_points(self, count: usize, label: &str) -> Self {
-        self.add_bytes(count * G::default().compressed_size(), label)
+        let encoding = self.point_encoding();
+        let size = count * encoding.size::<G>();
+        match encoding {
+            PointEncoding::Compressed => self.add_bytes(size, label),
+            _ => self.add_bytes(size, &format!("{}:{}", encoding.name(), label)),
+        }
     }
 }
 
 impl<G, H, C, const N: usize> GroupIOPattern<G> for IOPattern<H, Fp<C, N>>
 where
-    G: CurveGroup<BaseField = Fp<C, N>>,
+    G: AlgebraicPoint<BaseField = Fp<C, N>>,
     H: DuplexHash<Fp<C, N>>,
     C: FpConfig<N>,
     IOPattern<H, Fp<C, N>>: FieldIOPattern<Fp<C, N>>,
 {
+    /// Points are absorbed as [`AlgebraicPoint`] encodings,
+    /// and the label is prefixed with the curve model and coefficients.
     fn add_points(self, count: usize, label: &str) -> Self {
-        self.absorb(count * 2, label)
+        let label = encoding::curve_label::<G>() + label;
+        self.absorb(count * encoding::POINT_UNITS, &label)
+    }
+}
+
+impl<C, H, F2, const N: usize> NonNativeFieldIOPattern<F2> for IOPattern<H, Fp<C, N>>
+where
+    F2: PrimeField,
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+{
+    fn add_non_native_scalars(self, count: usize, label: &str) -> Self {
+        let limbs = common::non_native_limbs(F2::MODULUS_BIT_SIZE, Fp::<C, N>::MODULUS_BIT_SIZE);
+        self.absorb(count * limbs, label)
+    }
+
+    /// Non-native challenges are squeezed as bytes, cf. [`ByteIOPattern::challenge_bytes`].
+    fn challenge_non_native_scalars(self, count: usize, label: &str) -> Self {
+        self.challenge_bytes(count * bytes_uniform_modp(F2::MODULUS_BIT_SIZE), label)
+    }
+}
+
+impl<P, H> PairingIOPattern<P> for IOPattern<H>
+where
+    P: Pairing,
+    H: DuplexHash,
+{
+    fn add_g1_points(self, count: usize, label: &str) -> Self {
+        GroupIOPattern::<P::G1>::add_points(self, count, label)
+    }
+
+    fn add_g2_points(self, count: usize, label: &str) -> Self {
+        GroupIOPattern::<P::G2>::add_points(self, count, label)
+    }
+
+    fn add_gt_elements(self, count: usize, label: &str) -> Self {
+        self.add_bytes(
+            count * PairingOutput::<P>::default().compressed_size(),
+            label,
+        )
     }
 }
 
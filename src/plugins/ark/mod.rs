@@ -8,12 +8,11 @@
 //! or the serialization format.
 //!
 //! ```rust
-//! use ark_ec::CurveGroup;
 //! use ark_std::UniformRand;
 //! use nimue::{IOPattern, Merlin, DuplexHash, ProofResult};
 //! use nimue::plugins::ark::*;
 //!
-//! fn prove<G: CurveGroup>(
+//! fn prove<G: EncodableCurve>(
 //!     merlin: &mut Merlin,
 //!     x: G::ScalarField,
 //! ) -> ProofResult<&[u8]>
@@ -25,6 +24,8 @@
 //!     Ok(merlin.transcript())
 //! }
 //! ```
+//! Points are encoded with the [`PointEncoding`][`crate::PointEncoding`] of the IO Pattern,
+//! for any curve implementing [`EncodableCurve`][`crate::plugins::ark::EncodableCurve`], that is, any short Weierstrass or twisted Edwards curve.
 //! The type constraint on [`Merlin`][`crate::Merlin`] hints the compiler that we are going to be absorbing elements from the group `G` and squeezing challenges in the scalar field `G::ScalarField`. Similarly, we could have been squeezing out bytes.
 //!
 //! ```rust
@@ -60,7 +61,7 @@
 //! # use nimue::{IOPattern, Merlin, DuplexHash, ProofResult};
 //! # use nimue::plugins::ark::*;
 //!
-//! fn prove<G: CurveGroup, H: DuplexHash>(
+//! fn prove<G: EncodableCurve, H: DuplexHash>(
 //!     merlin: &mut Merlin<H>,
 //!     x: G::ScalarField,
 //! ) -> ProofResult<&[u8]>
@@ -115,16 +116,37 @@
 //!     Ok(merlin.transcript())
 //! }
 //! ```
-//! Now the above code should work with algebraic hashes such as [`PoseidonHash`][`crate::plugins::ark::poseidon::PoseidonHash`] just as fine as [`Keccak`][`crate::hash::Keccak`].
+//! Now the above code should work with algebraic hashes such as [`PoseidonHash`][`crate::plugins::ark::poseidon::PoseidonHash`],
+//! [`RescueHash`][`crate::plugins::ark::rescue::RescueHash`], or [`GriffinHash`][`crate::plugins::ark::griffin::GriffinHash`] just as fine as [`Keccak`][`crate::hash::Keccak`].
+//!
+//! To avoid the above conversion, elements of the scalar field can be sent as limbs over the base field with
+//! [`NonNativeFieldWriter`][`crate::plugins::ark::NonNativeFieldWriter`] and
+//! [`NonNativeFieldReader`][`crate::plugins::ark::NonNativeFieldReader`],
+//! and sampled with [`NonNativeFieldChallenges`][`crate::plugins::ark::NonNativeFieldChallenges`].
 //!
 /// Add public elements (field or group elements) to the protocol transcript.
 mod common;
+/// Point encodings.
+mod encoding;
+/// Support for the Griffin hash function.
+pub mod griffin;
+/// In-circuit transcripts for [`halo2_proofs`](https://github.com/zcash/halo2) over Poseidon.
+#[cfg(feature = "halo2")]
+pub mod halo2;
 /// IO Pattern utilities.
 mod iopattern;
 /// (WIP) Support for the Poseidon Hash function.
 pub mod poseidon;
+/// In-circuit verifier for transcripts over Poseidon.
+#[cfg(feature = "ark-r1cs-std")]
+pub mod r1cs;
 /// Veririfer's utilities for decoding a transcript.
 mod reader;
+/// Support for the Rescue-Prime hash function.
+pub mod rescue;
+/// Interoperability with the sponges of [`ark-crypto-primitives`](https://github.com/arkworks-rs/crypto-primitives).
+#[cfg(feature = "ark-crypto-primitives")]
+pub mod sponge;
 /// Prover's utilities for encoding into a transcript.
 mod writer;
 
@@ -136,9 +158,12 @@ mod tests;
 pub mod anemoi;
 
 pub use crate::traits::*;
-pub use crate::{hash::Unit, Arthur, DuplexHash, IOPattern, Merlin, ProofError, ProofResult, Safe};
+pub use crate::{
+    hash::Unit, Arthur, DuplexHash, IOPattern, Merlin, PointEncoding, ProofError, ProofResult, Safe,
+};
+pub use encoding::{AlgebraicPoint, EncodableCurve};
 
-super::traits::field_traits!(ark_ff::Field);
+super::traits::field_traits!(ark_ff::Field, short_scalars: common::fill_truncated_short_scalars);
 super::traits::group_traits!(ark_ec::CurveGroup, Scalar: ark_ff::PrimeField);
 
 /// Move a value from prime field F1 to prime field F2.
@@ -154,25 +179,88 @@ pub fn swap_field<F1: ark_ff::PrimeField, F2: ark_ff::PrimeField>(a_f1: F1) -> P
         .ok_or(ProofError::SerializationError)
 }
 
-// pub trait PairingReader<P: ark_ec::pairing::Pairing>: GroupReader<P::G1> + GroupReader<P::G2>  {
-//     fn fill_next_g1_points(&mut self, input: &mut [P::G1]) -> crate::ProofResult<()> {
-//         GroupReader::<P::G1>::fill_next_points(self, input)
-//     }
-
-//     fn fill_next_g2_points(&mut self, input: &mut [P::G2]) -> crate::ProofResult<()> {
-//         GroupReader::<P::G2>::fill_next_points(self, input)
-//     }
-// }
-// pub trait PairingWriter<P: ark_ec::pairing::Pairing> {
-//     fn add_g1_points(&mut self, input: &[P::G1]) -> crate::ProofResult<()> {
-//         GroupWriter::<P::G1>::add_points(self, input)
-//     }
-
-//     fn add_g2_points(&mut self, input: &[P::G2]) -> crate::ProofResult<()> {
-//         GroupWriter::<P::G2>::add_points(self, input)
-//     }
-// }
-
-// impl<'a, P: ark_ec::pairing::Pairing, H, U> PairingWriter<P> for Arthur<'a, H, U> where
-// U: Unit, H: DuplexHash<U>,
-// Arthur<'a, H, U>:  GroupWriter<P::G1> + GroupWriter<P::G2>  {}
+/// Send elements of a prime field other than the one of the sponge in the IO pattern.
+///
+/// Elements are absorbed as limbs of [`ark_ff::PrimeField::MODULUS_BIT_SIZE`] minus one bits of the field of the sponge,
+/// so that the decomposition of their canonical representative is injective.
+pub trait NonNativeFieldIOPattern<F: ark_ff::PrimeField> {
+    fn add_non_native_scalars(self, count: usize, label: &str) -> Self;
+    fn challenge_non_native_scalars(self, count: usize, label: &str) -> Self;
+}
+
+/// Add elements of a prime field other than the one of the sponge to the protocol transcript.
+pub trait NonNativeFieldWriter<F: ark_ff::PrimeField> {
+    fn add_non_native_scalars(&mut self, input: &[F]) -> ProofResult<()>;
+}
+
+/// Receive (and deserialize) elements of a prime field other than the one of the sponge from the protocol transcript.
+pub trait NonNativeFieldReader<F: ark_ff::PrimeField> {
+    fn fill_next_non_native_scalars(&mut self, output: &mut [F]) -> ProofResult<()>;
+
+    fn next_non_native_scalars<const N: usize>(&mut self) -> ProofResult<[F; N]> {
+        let mut output = [F::default(); N];
+        self.fill_next_non_native_scalars(&mut output)
+            .map(|()| output)
+    }
+}
+
+/// Interpret verifier messages as uniformly distributed elements of a prime field other than the one of the sponge.
+///
+/// Each element is reduced from `(MODULUS_BIT_SIZE + 128) / 8` bytes of [`ByteChallenges`].
+/// Since the bytes squeezed from the sponge are $2^{-128}$-close to uniform,
+/// each element is $2^{-127}$-close to uniform.
+pub trait NonNativeFieldChallenges<F: ark_ff::PrimeField> {
+    fn fill_challenge_non_native_scalars(&mut self, output: &mut [F]) -> ProofResult<()>;
+
+    fn challenge_non_native_scalars<const N: usize>(&mut self) -> ProofResult<[F; N]> {
+        let mut output = [F::default(); N];
+        self.fill_challenge_non_native_scalars(&mut output)
+            .map(|()| output)
+    }
+}
+
+/// Send elements of the groups of a pairing in the IO pattern.
+///
+/// Unlike [`GroupIOPattern`], the group is selected by the method name rather than by a type annotation.
+pub trait PairingIOPattern<P: ark_ec::pairing::Pairing> {
+    fn add_g1_points(self, count: usize, label: &str) -> Self;
+    fn add_g2_points(self, count: usize, label: &str) -> Self;
+    fn add_gt_elements(self, count: usize, label: &str) -> Self;
+}
+
+/// Add elements of the groups of a pairing to the protocol transcript.
+pub trait PairingWriter<P: ark_ec::pairing::Pairing> {
+    fn add_g1_points(&mut self, input: &[P::G1]) -> ProofResult<()>;
+    fn add_g2_points(&mut self, input: &[P::G2]) -> ProofResult<()>;
+    fn add_gt_elements(&mut self, input: &[ark_ec::pairing::PairingOutput<P>]) -> ProofResult<()>;
+}
+
+/// Receive (and deserialize) elements of the groups of a pairing from the protocol transcript.
+///
+/// The implementation of this trait **MUST** ensure that the elements decoded are
+/// valid group elements, i.e. that they lie in the prime-order subgroups.
+pub trait PairingReader<P: ark_ec::pairing::Pairing> {
+    fn fill_next_g1_points(&mut self, output: &mut [P::G1]) -> ProofResult<()>;
+    fn fill_next_g2_points(&mut self, output: &mut [P::G2]) -> ProofResult<()>;
+    fn fill_next_gt_elements(
+        &mut self,
+        output: &mut [ark_ec::pairing::PairingOutput<P>],
+    ) -> ProofResult<()>;
+
+    fn next_g1_points<const N: usize>(&mut self) -> ProofResult<[P::G1; N]> {
+        let mut output = [P::G1::default(); N];
+        self.fill_next_g1_points(&mut output).map(|()| output)
+    }
+
+    fn next_g2_points<const N: usize>(&mut self) -> ProofResult<[P::G2; N]> {
+        let mut output = [P::G2::default(); N];
+        self.fill_next_g2_points(&mut output).map(|()| output)
+    }
+
+    fn next_gt_elements<const N: usize>(
+        &mut self,
+    ) -> ProofResult<[ark_ec::pairing::PairingOutput<P>; N]> {
+        let mut output = [ark_ec::pairing::PairingOutput::default(); N];
+        self.fill_next_gt_elements(&mut output).map(|()| output)
+    }
+}
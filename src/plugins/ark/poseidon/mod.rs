@@ -2,7 +2,7 @@
 //! from William Lin, with contributions from Pratyush Mishra, Weikeng Chen, Yuwen Zhang, Kristian Sosnin, Merlyn, Wilson Nguyen, Hossein Moghaddas, and others.
 use ark_ff::PrimeField;
 
-use crate::hash::sponge::Sponge;
+use crate::hash::sponge::{algebraic_security_level, Sponge};
 use crate::hash::Unit;
 
 #[derive(Clone)]
@@ -86,6 +86,7 @@ where
     type U = F;
     const N: usize = N;
     const R: usize = R;
+    const SECURITY_LEVEL: usize = algebraic_security_level(N - R, F::MODULUS_BIT_SIZE);
 
     fn new(iv: [u8; 32]) -> Self {
         assert!(N >= 1);
@@ -0,0 +1,191 @@
+//! An in-circuit verifier for transcripts over [`PoseidonHash`][`crate::plugins::ark::poseidon::PoseidonHash`], using [`ark_r1cs_std`].
+//!
+//! [`ArthurVar`][`crate::plugins::ark::r1cs::ArthurVar`] re-derives, inside a constraint system, the same challenges that [`Arthur`][`crate::Arthur`]
+//! derives natively: it replays the absorb, squeeze, and ratchet operations of the IO Pattern exactly as
+//! [`Safe`][`crate::Safe`] and [`DuplexSponge`][`crate::hash::sponge::DuplexSponge`] do.
+//! The IO Pattern is public, hence the IV is a constant of the circuit.
+use std::collections::VecDeque;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::{fp::FpVar, FieldVar};
+use ark_relations::r1cs::SynthesisError;
+
+use super::poseidon::{PoseidonHash, PoseidonSponge};
+use crate::hash::Unit;
+use crate::iopattern::{pop_op, Op};
+use crate::{IOPattern, Safe};
+
+/// The in-circuit counterpart of [`PoseidonHash`]:
+/// a duplex sponge in overwrite mode over the Poseidon permutation.
+#[derive(Clone)]
+pub struct PoseidonSpongeVar<F: PrimeField, const R: usize, const N: usize> {
+    /// The parameters of the permutation. The native state is unused.
+    parameters: PoseidonSponge<F, R, N>,
+    state: Vec<FpVar<F>>,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> PoseidonSpongeVar<F, R, N> {
+    /// Initialize the sponge with the given parameters, writing the IV in the capacity
+    /// as [`PoseidonSponge`] does.
+    pub fn new(parameters: &PoseidonSponge<F, R, N>, iv: [u8; 32]) -> Self {
+        assert!(N > R, "Capacity of the sponge should be > 0.");
+        let mut state = vec![FpVar::zero(); N];
+        state[R] = FpVar::constant(F::from_be_bytes_mod_order(&iv));
+        Self {
+            parameters: parameters.clone(),
+            state,
+            absorb_pos: 0,
+            squeeze_pos: R,
+        }
+    }
+
+    fn permute(&mut self) -> Result<(), SynthesisError> {
+        let params = &self.parameters;
+        let full_rounds_over_2 = params.full_rounds / 2;
+        let total_rounds = params.full_rounds + params.partial_rounds;
+        for round in 0..total_rounds {
+            let is_full_round =
+                round < full_rounds_over_2 || round >= full_rounds_over_2 + params.partial_rounds;
+            // Add round constants
+            for (x, c) in self.state.iter_mut().zip(&params.ark[round]) {
+                *x += *c;
+            }
+            // S-box
+            let sboxes = if is_full_round { N } else { 1 };
+            for x in self.state[..sboxes].iter_mut() {
+                *x = x.pow_by_constant([params.alpha])?;
+            }
+            // MDS
+            self.state = params
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(&self.state)
+                        .map(|(m, x)| x * *m)
+                        .sum::<FpVar<F>>()
+                })
+                .collect();
+        }
+        Ok(())
+    }
+
+    /// Absorb `input` into the sponge.
+    pub fn absorb(&mut self, mut input: &[FpVar<F>]) -> Result<(), SynthesisError> {
+        while !input.is_empty() {
+            if self.absorb_pos == R {
+                self.permute()?;
+                self.absorb_pos = 0;
+            } else {
+                let chunk_len = usize::min(input.len(), R - self.absorb_pos);
+                let (chunk, rest) = input.split_at(chunk_len);
+                self.state[self.absorb_pos..self.absorb_pos + chunk_len].clone_from_slice(chunk);
+                self.absorb_pos += chunk_len;
+                input = rest;
+            }
+        }
+        self.squeeze_pos = R;
+        Ok(())
+    }
+
+    /// Fill `output` with elements squeezed from the sponge.
+    pub fn squeeze(&mut self, mut output: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+        while !output.is_empty() {
+            if self.squeeze_pos == R {
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+                self.permute()?;
+            }
+            let chunk_len = usize::min(output.len(), R - self.squeeze_pos);
+            let (chunk, rest) = output.split_at_mut(chunk_len);
+            chunk.clone_from_slice(&self.state[self.squeeze_pos..self.squeeze_pos + chunk_len]);
+            self.squeeze_pos += chunk_len;
+            output = rest;
+        }
+        Ok(())
+    }
+
+    /// Permute the state and zero the rate.
+    pub fn ratchet(&mut self) -> Result<(), SynthesisError> {
+        self.permute()?;
+        self.state[..R].fill(FpVar::zero());
+        self.squeeze_pos = R;
+        Ok(())
+    }
+}
+
+/// The in-circuit counterpart of [`Arthur`][`crate::Arthur`] over [`PoseidonHash`].
+///
+/// The transcript is given as a vector of field element variables, allocated by the caller.
+/// Any deviation from the IO Pattern results in [`SynthesisError::Unsatisfiable`].
+pub struct ArthurVar<F: PrimeField, const R: usize, const N: usize> {
+    sponge: PoseidonSpongeVar<F, R, N>,
+    stack: VecDeque<Op>,
+    transcript: VecDeque<FpVar<F>>,
+}
+
+impl<F, const R: usize, const N: usize> ArthurVar<F, R, N>
+where
+    F: PrimeField + Unit,
+    PoseidonSponge<F, R, N>: Default,
+{
+    /// Create a new verifier for `io_pattern`, reading the prover's messages from `transcript`.
+    /// The permutation is instantiated with `parameters`, which must be the ones of the native sponge.
+    pub fn new(
+        io_pattern: &IOPattern<PoseidonHash<F, R, N>, F>,
+        transcript: Vec<FpVar<F>>,
+        parameters: &PoseidonSponge<F, R, N>,
+    ) -> Self {
+        let iv = Safe::<PoseidonHash<F, R, N>, F>::generate_tag(io_pattern.as_bytes());
+        Self {
+            sponge: PoseidonSpongeVar::new(parameters, iv),
+            stack: io_pattern.finalize(),
+            transcript: transcript.into(),
+        }
+    }
+
+    /// Check that the next operation is `expected`, cf. [`pop_op`].
+    fn pop_op(&mut self, expected: Op) -> Result<(), SynthesisError> {
+        pop_op(&mut self.stack, expected).map_err(|e| {
+            log::error!("{}", e);
+            SynthesisError::Unsatisfiable
+        })
+    }
+
+    /// Absorb public elements.
+    pub fn public_units(&mut self, input: &[FpVar<F>]) -> Result<(), SynthesisError> {
+        if input.is_empty() {
+            return Ok(());
+        }
+        self.pop_op(Op::Absorb(input.len()))?;
+        self.sponge.absorb(input)
+    }
+
+    /// Read the next `count` elements of the transcript, and absorb them.
+    pub fn next_units(&mut self, count: usize) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        if self.transcript.len() < count {
+            return Err(SynthesisError::AssignmentMissing);
+        }
+        let units = self.transcript.drain(..count).collect::<Vec<_>>();
+        self.public_units(&units)?;
+        Ok(units)
+    }
+
+    /// Squeeze `count` challenges.
+    pub fn challenge_units(&mut self, count: usize) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let mut output = vec![FpVar::zero(); count];
+        if count > 0 {
+            self.pop_op(Op::Squeeze(count))?;
+            self.sponge.squeeze(&mut output)?;
+        }
+        Ok(output)
+    }
+
+    /// Ratchet the sponge.
+    pub fn ratchet(&mut self) -> Result<(), SynthesisError> {
+        self.pop_op(Op::Ratchet)?;
+        self.sponge.ratchet()
+    }
+}
@@ -1,13 +1,16 @@
+use ark_ec::pairing::{Pairing, PairingOutput};
 use ark_ec::short_weierstrass::{Affine as SWAffine, Projective as SWCurve, SWCurveConfig};
 use ark_ec::twisted_edwards::{Affine as EdwardsAffine, Projective as EdwardsCurve, TECurveConfig};
-use ark_ec::CurveGroup;
-use ark_ff::Field;
+use ark_ff::{Field, PrimeField};
 use ark_ff::{Fp, FpConfig};
-use ark_serialize::CanonicalDeserialize;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 
-use super::{FieldReader, GroupReader};
+use super::common::to_non_native_limbs;
+use super::{
+    AlgebraicPoint, EncodableCurve, FieldReader, GroupReader, NonNativeFieldReader, PairingReader,
+};
 use crate::traits::*;
-use crate::{Arthur, DuplexHash, ProofResult};
+use crate::{Arthur, DuplexHash, IOPatternError, ProofResult};
 
 impl<'a, F, H> FieldReader<F> for Arthur<'a, H>
 where
@@ -25,30 +28,87 @@ where
     }
 }
 
+/// Points are read with the [`PointEncoding`][`crate::PointEncoding`] of the IO Pattern.
 impl<'a, G, H> GroupReader<G> for Arthur<'a, H>
 where
-    G: CurveGroup,
+    G: EncodableCurve,
     H: DuplexHash,
 {
     fn fill_next_points(&mut self, output: &mut [G]) -> ProofResult<()> {
-        let point_size = G::default().compressed_size();
-        let mut buf = vec![0u8; point_size];
+        let encoding = self.safe.point_encoding();
+        let mut buf = vec![0u8; encoding.size::<G>()];
 
         for o in output.iter_mut() {
             self.fill_next_units(&mut buf)?;
-            *o = G::deserialize_compressed(buf.as_slice())?;
+            *o = encoding.decode(&buf)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, P, H> PairingReader<P> for Arthur<'a, H>
+where
+    P: Pairing,
+    P::G1: EncodableCurve,
+    P::G2: EncodableCurve,
+    H: DuplexHash,
+{
+    fn fill_next_g1_points(&mut self, output: &mut [P::G1]) -> ProofResult<()> {
+        GroupReader::<P::G1>::fill_next_points(self, output)
+    }
+
+    fn fill_next_g2_points(&mut self, output: &mut [P::G2]) -> ProofResult<()> {
+        GroupReader::<P::G2>::fill_next_points(self, output)
+    }
+
+    /// Target group elements are checked to be in the subgroup of order $r$.
+    fn fill_next_gt_elements(&mut self, output: &mut [PairingOutput<P>]) -> ProofResult<()> {
+        let size = PairingOutput::<P>::default().compressed_size();
+        let mut buf = vec![0u8; size];
+        for o in output.iter_mut() {
+            self.fill_next_bytes(&mut buf)?;
+            *o = PairingOutput::deserialize_compressed(buf.as_slice())?;
         }
         Ok(())
     }
 }
 
-impl<'a, H, C, const N: usize> FieldReader<Fp<C, N>> for Arthur<'a, H, Fp<C, N>>
+impl<'a, H, C, F, const N: usize> FieldReader<F> for Arthur<'a, H, Fp<C, N>>
+where
+    F: Field<BasePrimeField = Fp<C, N>>,
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+{
+    fn fill_next_scalars(&mut self, output: &mut [F]) -> crate::ProofResult<()> {
+        let mut units = vec![Fp::from(0); output.len() * F::extension_degree() as usize];
+        self.fill_next_units(&mut units)?;
+        super::common::from_base_prime_field_units(&units, output);
+        Ok(())
+    }
+}
+
+impl<'a, H, C, const N: usize> ByteReader for Arthur<'a, H, Fp<C, N>>
+where
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+{
+    fn fill_next_bytes(&mut self, input: &mut [u8]) -> Result<(), IOPatternError> {
+        std::io::Read::read_exact(&mut self.transcript, input)?;
+        self.public_bytes(input)
+    }
+}
+
+impl<'a, F2, H, C, const N: usize> NonNativeFieldReader<F2> for Arthur<'a, H, Fp<C, N>>
 where
+    F2: PrimeField,
     C: FpConfig<N>,
     H: DuplexHash<Fp<C, N>>,
 {
-    fn fill_next_scalars(&mut self, output: &mut [Fp<C, N>]) -> crate::ProofResult<()> {
-        self.fill_next_units(output)?;
+    fn fill_next_non_native_scalars(&mut self, output: &mut [F2]) -> ProofResult<()> {
+        for o in output.iter_mut() {
+            *o = F2::deserialize_compressed(&mut self.transcript)?;
+            self.public_units(&to_non_native_limbs::<F2, Fp<C, N>>(o))?;
+        }
         Ok(())
     }
 }
@@ -63,7 +123,7 @@ where
         for o in output.iter_mut() {
             let o_affine = EdwardsAffine::deserialize_compressed(&mut self.transcript)?;
             *o = o_affine.into();
-            self.public_units(&[o.x, o.y])?;
+            self.public_units(&o.to_algebraic_units())?;
         }
         Ok(())
     }
@@ -79,7 +139,7 @@ where
         for o in output.iter_mut() {
             let o_affine = SWAffine::deserialize_compressed(&mut self.transcript)?;
             *o = o_affine.into();
-            self.public_units(&[o.x, o.y])?;
+            self.public_units(&o.to_algebraic_units())?;
         }
         Ok(())
     }
@@ -0,0 +1,18 @@
+use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+use crate::plugins::ark::rescue;
+
+mod rp64_256;
+
+#[derive(MontConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct F64Config;
+
+/// The prime field of order $p = 2^{64} - 2^{32} + 1$, as used in Winterfell.
+pub type F64 = Fp64<MontBackend<F64Config, 1>>;
+
+// Rescue-Prime with the parameters of Winterfell's `Rp64_256`:
+// state width 12, capacity 4, 7 rounds, S-box degree 7,
+// its circulant MDS matrix, and its round constants.
+rescue_sponge!(Rp64_256, rp64_256);
@@ -0,0 +1,384 @@
+//! Generated with `scripts/rescue_prime_constants.py`.
+use ark_ff::MontFp;
+
+use super::F64;
+
+pub type Field = F64;
+pub const ALPHA: u64 = 7;
+pub const ALPHA_INV: &[u64] = &[10540996611094048183];
+pub const ROUNDS: usize = 7;
+pub const N: usize = 12;
+pub const R: usize = 8;
+
+pub const MDS: &[[F64; 12]] = &[
+    [
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+    ],
+    [
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+    ],
+    [
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+    ],
+    [
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+    ],
+    [
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+    ],
+    [
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+    ],
+    [
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+    ],
+    [
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+    ],
+    [
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+    ],
+    [
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+        MontFp!("8"),
+    ],
+    [
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+        MontFp!("23"),
+    ],
+    [
+        MontFp!("23"),
+        MontFp!("8"),
+        MontFp!("26"),
+        MontFp!("13"),
+        MontFp!("10"),
+        MontFp!("9"),
+        MontFp!("7"),
+        MontFp!("6"),
+        MontFp!("22"),
+        MontFp!("21"),
+        MontFp!("8"),
+        MontFp!("7"),
+    ],
+];
+
+pub const ARK1: &[[F64; 12]] = &[
+    [
+        MontFp!("5789762306288267392"),
+        MontFp!("6522564764413701783"),
+        MontFp!("17809893479458208203"),
+        MontFp!("107145243989736508"),
+        MontFp!("6388978042437517382"),
+        MontFp!("15844067734406016715"),
+        MontFp!("9975000513555218239"),
+        MontFp!("3344984123768313364"),
+        MontFp!("9959189626657347191"),
+        MontFp!("12960773468763563665"),
+        MontFp!("9602914297752488475"),
+        MontFp!("16657542370200465908"),
+    ],
+    [
+        MontFp!("12987190162843096997"),
+        MontFp!("653957632802705281"),
+        MontFp!("4441654670647621225"),
+        MontFp!("4038207883745915761"),
+        MontFp!("5613464648874830118"),
+        MontFp!("13222989726778338773"),
+        MontFp!("3037761201230264149"),
+        MontFp!("16683759727265180203"),
+        MontFp!("8337364536491240715"),
+        MontFp!("3227397518293416448"),
+        MontFp!("8110510111539674682"),
+        MontFp!("2872078294163232137"),
+    ],
+    [
+        MontFp!("18072785500942327487"),
+        MontFp!("6200974112677013481"),
+        MontFp!("17682092219085884187"),
+        MontFp!("10599526828986756440"),
+        MontFp!("975003873302957338"),
+        MontFp!("8264241093196931281"),
+        MontFp!("10065763900435475170"),
+        MontFp!("2181131744534710197"),
+        MontFp!("6317303992309418647"),
+        MontFp!("1401440938888741532"),
+        MontFp!("8884468225181997494"),
+        MontFp!("13066900325715521532"),
+    ],
+    [
+        MontFp!("5674685213610121970"),
+        MontFp!("5759084860419474071"),
+        MontFp!("13943282657648897737"),
+        MontFp!("1352748651966375394"),
+        MontFp!("17110913224029905221"),
+        MontFp!("1003883795902368422"),
+        MontFp!("4141870621881018291"),
+        MontFp!("8121410972417424656"),
+        MontFp!("14300518605864919529"),
+        MontFp!("13712227150607670181"),
+        MontFp!("17021852944633065291"),
+        MontFp!("6252096473787587650"),
+    ],
+    [
+        MontFp!("4887609836208846458"),
+        MontFp!("3027115137917284492"),
+        MontFp!("9595098600469470675"),
+        MontFp!("10528569829048484079"),
+        MontFp!("7864689113198939815"),
+        MontFp!("17533723827845969040"),
+        MontFp!("5781638039037710951"),
+        MontFp!("17024078752430719006"),
+        MontFp!("109659393484013511"),
+        MontFp!("7158933660534805869"),
+        MontFp!("2955076958026921730"),
+        MontFp!("7433723648458773977"),
+    ],
+    [
+        MontFp!("16308865189192447297"),
+        MontFp!("11977192855656444890"),
+        MontFp!("12532242556065780287"),
+        MontFp!("14594890931430968898"),
+        MontFp!("7291784239689209784"),
+        MontFp!("5514718540551361949"),
+        MontFp!("10025733853830934803"),
+        MontFp!("7293794580341021693"),
+        MontFp!("6728552937464861756"),
+        MontFp!("6332385040983343262"),
+        MontFp!("13277683694236792804"),
+        MontFp!("2600778905124452676"),
+    ],
+    [
+        MontFp!("7123075680859040534"),
+        MontFp!("1034205548717903090"),
+        MontFp!("7717824418247931797"),
+        MontFp!("3019070937878604058"),
+        MontFp!("11403792746066867460"),
+        MontFp!("10280580802233112374"),
+        MontFp!("337153209462421218"),
+        MontFp!("13333398568519923717"),
+        MontFp!("3596153696935337464"),
+        MontFp!("8104208463525993784"),
+        MontFp!("14345062289456085693"),
+        MontFp!("17036731477169661256"),
+    ],
+];
+
+pub const ARK2: &[[F64; 12]] = &[
+    [
+        MontFp!("6077062762357204287"),
+        MontFp!("15277620170502011191"),
+        MontFp!("5358738125714196705"),
+        MontFp!("14233283787297595718"),
+        MontFp!("13792579614346651365"),
+        MontFp!("11614812331536767105"),
+        MontFp!("14871063686742261166"),
+        MontFp!("10148237148793043499"),
+        MontFp!("4457428952329675767"),
+        MontFp!("15590786458219172475"),
+        MontFp!("10063319113072092615"),
+        MontFp!("14200078843431360086"),
+    ],
+    [
+        MontFp!("6202948458916099932"),
+        MontFp!("17690140365333231091"),
+        MontFp!("3595001575307484651"),
+        MontFp!("373995945117666487"),
+        MontFp!("1235734395091296013"),
+        MontFp!("14172757457833931602"),
+        MontFp!("707573103686350224"),
+        MontFp!("15453217512188187135"),
+        MontFp!("219777875004506018"),
+        MontFp!("17876696346199469008"),
+        MontFp!("17731621626449383378"),
+        MontFp!("2897136237748376248"),
+    ],
+    [
+        MontFp!("8023374565629191455"),
+        MontFp!("15013690343205953430"),
+        MontFp!("4485500052507912973"),
+        MontFp!("12489737547229155153"),
+        MontFp!("9500452585969030576"),
+        MontFp!("2054001340201038870"),
+        MontFp!("12420704059284934186"),
+        MontFp!("355990932618543755"),
+        MontFp!("9071225051243523860"),
+        MontFp!("12766199826003448536"),
+        MontFp!("9045979173463556963"),
+        MontFp!("12934431667190679898"),
+    ],
+    [
+        MontFp!("18389244934624494276"),
+        MontFp!("16731736864863925227"),
+        MontFp!("4440209734760478192"),
+        MontFp!("17208448209698888938"),
+        MontFp!("8739495587021565984"),
+        MontFp!("17000774922218161967"),
+        MontFp!("13533282547195532087"),
+        MontFp!("525402848358706231"),
+        MontFp!("16987541523062161972"),
+        MontFp!("5466806524462797102"),
+        MontFp!("14512769585918244983"),
+        MontFp!("10973956031244051118"),
+    ],
+    [
+        MontFp!("6982293561042362913"),
+        MontFp!("14065426295947720331"),
+        MontFp!("16451845770444974180"),
+        MontFp!("7139138592091306727"),
+        MontFp!("9012006439959783127"),
+        MontFp!("14619614108529063361"),
+        MontFp!("1394813199588124371"),
+        MontFp!("4635111139507788575"),
+        MontFp!("16217473952264203365"),
+        MontFp!("10782018226466330683"),
+        MontFp!("6844229992533662050"),
+        MontFp!("7446486531695178711"),
+    ],
+    [
+        MontFp!("3736792340494631448"),
+        MontFp!("577852220195055341"),
+        MontFp!("6689998335515779805"),
+        MontFp!("13886063479078013492"),
+        MontFp!("14358505101923202168"),
+        MontFp!("7744142531772274164"),
+        MontFp!("16135070735728404443"),
+        MontFp!("12290902521256031137"),
+        MontFp!("12059913662657709804"),
+        MontFp!("16456018495793751911"),
+        MontFp!("4571485474751953524"),
+        MontFp!("17200392109565783176"),
+    ],
+    [
+        MontFp!("17130398059294018733"),
+        MontFp!("519782857322261988"),
+        MontFp!("9625384390925085478"),
+        MontFp!("1664893052631119222"),
+        MontFp!("7629576092524553570"),
+        MontFp!("3485239601103661425"),
+        MontFp!("9755891797164033838"),
+        MontFp!("15218148195153269027"),
+        MontFp!("16460604813734957368"),
+        MontFp!("9643968136937729763"),
+        MontFp!("3611348709641382851"),
+        MontFp!("18256379591337759196"),
+    ],
+];
@@ -0,0 +1,133 @@
+//! The [Rescue-Prime](https://eprint.iacr.org/2020/1143) permutation.
+//!
+//! Each round applies the S-box $x \mapsto x^\alpha$, the MDS matrix, and the first set of round constants,
+//! followed by the inverse S-box $x \mapsto x^{1/\alpha}$, the MDS matrix, and the second set of round constants.
+use ark_ff::PrimeField;
+
+use crate::hash::sponge::{algebraic_security_level, Sponge};
+use crate::hash::Unit;
+
+#[derive(Clone)]
+pub struct RescueState<F: PrimeField, const R: usize, const N: usize> {
+    /// Number of rounds.
+    pub rounds: usize,
+    /// Exponent used in the S-boxes.
+    pub alpha: u64,
+    /// Exponent used in the inverse S-boxes, i.e. $\alpha^{-1} \bmod (p-1)$, as little-endian 64-bit limbs.
+    pub alpha_inv: &'static [u64],
+    /// Round constants added after the first half of each round.
+    /// They are indexed by `ark1[round_num][state_element_index]`
+    pub ark1: &'static [[F; N]],
+    /// Round constants added after the second half of each round.
+    /// They are indexed by `ark2[round_num][state_element_index]`
+    pub ark2: &'static [[F; N]],
+    /// Maximally Distance Separating (MDS) Matrix.
+    pub mds: &'static [[F; N]],
+
+    /// Sponge state
+    pub state: [F; N],
+}
+
+pub type RescueHash<F, const R: usize, const N: usize> =
+    crate::hash::sponge::DuplexSponge<RescueState<F, R, N>>;
+
+impl<F: PrimeField, const R: usize, const N: usize> AsRef<[F]> for RescueState<F, R, N> {
+    fn as_ref(&self) -> &[F] {
+        &self.state
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> AsMut<[F]> for RescueState<F, R, N> {
+    fn as_mut(&mut self) -> &mut [F] {
+        &mut self.state
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> RescueState<F, R, N> {
+    fn apply_s_box(state: &mut [F], exponent: &[u64]) {
+        for elem in state.iter_mut() {
+            *elem = elem.pow(exponent);
+        }
+    }
+
+    fn apply_ark(state: &mut [F], constants: &[F; N]) {
+        for (state_elem, c) in state.iter_mut().zip(constants) {
+            state_elem.add_assign(c);
+        }
+    }
+
+    fn apply_mds(&self, state: &mut [F; N]) {
+        let mut new_state = [F::zero(); N];
+        for (i, new_elem) in new_state.iter_mut().enumerate() {
+            for (j, state_elem) in state.iter().enumerate() {
+                *new_elem += *state_elem * self.mds[i][j];
+            }
+        }
+        *state = new_state;
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> zeroize::Zeroize for RescueState<F, R, N> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl<F, const R: usize, const N: usize> Sponge for RescueState<F, R, N>
+where
+    RescueState<F, R, N>: Default,
+    F: PrimeField + Unit,
+{
+    type U = F;
+    const N: usize = N;
+    const R: usize = R;
+    const SECURITY_LEVEL: usize = algebraic_security_level(N - R, F::MODULUS_BIT_SIZE);
+
+    fn new(iv: [u8; 32]) -> Self {
+        assert!(N >= 1);
+        let mut sponge = Self::default();
+        sponge.state[R] = F::from_be_bytes_mod_order(&iv);
+        sponge
+    }
+
+    fn permute(&mut self) {
+        let mut state = self.state;
+        for round in 0..self.rounds {
+            Self::apply_s_box(&mut state, &[self.alpha]);
+            self.apply_mds(&mut state);
+            Self::apply_ark(&mut state, &self.ark1[round]);
+
+            Self::apply_s_box(&mut state, self.alpha_inv);
+            self.apply_mds(&mut state);
+            Self::apply_ark(&mut state, &self.ark2[round]);
+        }
+        self.state = state;
+    }
+}
+
+/// Initialization of constants.
+#[allow(unused)]
+macro_rules! rescue_sponge {
+    ($name: ident, $path: tt) => {
+        pub type $name = crate::hash::sponge::DuplexSponge<
+            rescue::RescueState<$path::Field, { $path::R }, { $path::N }>,
+        >;
+
+        impl Default for rescue::RescueState<$path::Field, { $path::R }, { $path::N }> {
+            fn default() -> Self {
+                Self {
+                    rounds: $path::ROUNDS,
+                    alpha: $path::ALPHA,
+                    alpha_inv: $path::ALPHA_INV,
+                    ark1: $path::ARK1,
+                    ark2: $path::ARK2,
+                    mds: $path::MDS,
+                    state: [ark_ff::Zero::zero(); $path::N],
+                }
+            }
+        }
+    };
+}
+
+/// Rescue-Prime over the 64-bit prime field $p = 2^{64} - 2^{32} + 1$.
+pub mod f64;
@@ -0,0 +1,318 @@
+//! Interoperability with [`ark_crypto_primitives::sponge`].
+//!
+//! - [`Merlin`] and [`Arthur`] implement [`CryptographicSponge`][`ark_crypto_primitives::sponge::CryptographicSponge`]: absorbed elements are *public* (cf. [`UnitTranscript::public_units`])
+//!     and squeezed elements are verifier's challenges, as specified by the [`IOPattern`].
+//!     Since the interface of [`CryptographicSponge`][`ark_crypto_primitives::sponge::CryptographicSponge`] is infallible, any deviation from the IO Pattern **panics**.
+//! - [`DuplexSponge`][`crate::hash::sponge::DuplexSponge`]s over prime fields, such as
+//!     [`PoseidonHash`][`crate::plugins::ark::poseidon::PoseidonHash`],
+//!     implement [`CryptographicSponge`][`ark_crypto_primitives::sponge::CryptographicSponge`] too.
+//! - [`SpongeBridge`][`crate::plugins::ark::sponge::SpongeBridge`] wraps any
+//!     [`FieldBasedCryptographicSponge`][`ark_crypto_primitives::sponge::FieldBasedCryptographicSponge`]
+//!     as a [`DuplexHash`][`crate::DuplexHash`].
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::{BigInteger, Fp, FpConfig, PrimeField};
+use rand::{CryptoRng, RngCore};
+
+use crate::hash::sponge::{DuplexSponge, Sponge};
+use crate::{Arthur, ByteChallenges, DuplexHash, IOPattern, Merlin, Unit, UnitTranscript};
+
+/// Squeeze `num_bytes` bytes out of field elements, using all but the most significant bit of each element
+/// (as [`ark_crypto_primitives::sponge::poseidon::PoseidonSponge`] does).
+fn squeeze_bytes_from_elements<F: PrimeField>(
+    num_bytes: usize,
+    squeeze: impl FnOnce(usize) -> Vec<F>,
+) -> Vec<u8> {
+    let usable_bytes = ((F::MODULUS_BIT_SIZE - 1) / 8) as usize;
+    let elements = squeeze(num_bytes.div_ceil(usable_bytes));
+    let mut bytes = elements
+        .iter()
+        .flat_map(|x| x.into_bigint().to_bytes_le()[..usable_bytes].to_vec())
+        .collect::<Vec<_>>();
+    bytes.truncate(num_bytes);
+    bytes
+}
+
+/// Squeeze `num_bits` bits out of field elements, using all but the most significant bit of each element.
+fn squeeze_bits_from_elements<F: PrimeField>(
+    num_bits: usize,
+    squeeze: impl FnOnce(usize) -> Vec<F>,
+) -> Vec<bool> {
+    let usable_bits = (F::MODULUS_BIT_SIZE - 1) as usize;
+    let elements = squeeze(num_bits.div_ceil(usable_bits));
+    let mut bits = elements
+        .iter()
+        .flat_map(|x| x.into_bigint().to_bits_le()[..usable_bits].to_vec())
+        .collect::<Vec<_>>();
+    bits.truncate(num_bits);
+    bits
+}
+
+/// Little-endian bits of the given bytes.
+fn bytes_to_bits(bytes: &[u8], num_bits: usize) -> Vec<bool> {
+    (0..num_bits)
+        .map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1)
+        .collect()
+}
+
+/// Squeeze `num_elements` challenges from a transcript over `U`.
+fn squeeze_units<U: Unit + Default, T: UnitTranscript<U>>(
+    transcript: &mut T,
+    num_elements: usize,
+) -> Vec<U> {
+    let mut output = vec![U::default(); num_elements];
+    transcript
+        .fill_challenge_units(&mut output)
+        .expect("Squeezing does not follow the IO Pattern");
+    output
+}
+
+impl<H, R> CryptographicSponge for Merlin<H, u8, R>
+where
+    H: DuplexHash<u8>,
+    R: RngCore + CryptoRng + Clone + Default,
+{
+    type Config = IOPattern<H, u8>;
+
+    fn new(io_pattern: &Self::Config) -> Self {
+        Merlin::new(io_pattern, R::default())
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        self.public_units(&input.to_sponge_bytes_as_vec())
+            .expect("Absorbing does not follow the IO Pattern");
+    }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        squeeze_units(self, num_bytes)
+    }
+
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        bytes_to_bits(&squeeze_units(self, num_bits.div_ceil(8)), num_bits)
+    }
+}
+
+impl<'a, H> CryptographicSponge for Arthur<'a, H, u8>
+where
+    H: DuplexHash<u8>,
+{
+    type Config = (IOPattern<H, u8>, &'a [u8]);
+
+    fn new((io_pattern, transcript): &Self::Config) -> Self {
+        Arthur::new(io_pattern, transcript)
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        self.public_units(&input.to_sponge_bytes_as_vec())
+            .expect("Absorbing does not follow the IO Pattern");
+    }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        let mut output = vec![0u8; num_bytes];
+        self.fill_challenge_bytes(&mut output)
+            .expect("Squeezing does not follow the IO Pattern");
+        output
+    }
+
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        bytes_to_bits(&self.squeeze_bytes(num_bits.div_ceil(8)), num_bits)
+    }
+}
+
+impl<H, R, C, const N: usize> CryptographicSponge for Merlin<H, Fp<C, N>, R>
+where
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+    R: RngCore + CryptoRng + Clone + Default,
+{
+    type Config = IOPattern<H, Fp<C, N>>;
+
+    fn new(io_pattern: &Self::Config) -> Self {
+        Merlin::new(io_pattern, R::default())
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        self.public_units(&input.to_sponge_field_elements_as_vec())
+            .expect("Absorbing does not follow the IO Pattern");
+    }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        squeeze_bytes_from_elements(num_bytes, |n| squeeze_units(self, n))
+    }
+
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        squeeze_bits_from_elements(num_bits, |n| squeeze_units(self, n))
+    }
+}
+
+impl<H, R, C, const N: usize> FieldBasedCryptographicSponge<Fp<C, N>> for Merlin<H, Fp<C, N>, R>
+where
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+    R: RngCore + CryptoRng + Clone + Default,
+{
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<Fp<C, N>> {
+        squeeze_units(self, num_elements)
+    }
+}
+
+impl<'a, H, C, const N: usize> CryptographicSponge for Arthur<'a, H, Fp<C, N>>
+where
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+{
+    type Config = (IOPattern<H, Fp<C, N>>, &'a [u8]);
+
+    fn new((io_pattern, transcript): &Self::Config) -> Self {
+        Arthur::new(io_pattern, transcript)
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        self.public_units(&input.to_sponge_field_elements_as_vec())
+            .expect("Absorbing does not follow the IO Pattern");
+    }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        squeeze_bytes_from_elements(num_bytes, |n| squeeze_units(self, n))
+    }
+
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        squeeze_bits_from_elements(num_bits, |n| squeeze_units(self, n))
+    }
+}
+
+impl<'a, H, C, const N: usize> FieldBasedCryptographicSponge<Fp<C, N>> for Arthur<'a, H, Fp<C, N>>
+where
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+{
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<Fp<C, N>> {
+        squeeze_units(self, num_elements)
+    }
+}
+
+/// Squeeze `num_elements` elements from a duplex sponge.
+fn squeeze_sponge<C: Sponge>(sponge: &mut DuplexSponge<C>, num_elements: usize) -> Vec<C::U>
+where
+    C::U: Default,
+{
+    let mut output = vec![C::U::default(); num_elements];
+    sponge.squeeze_unchecked(&mut output);
+    output
+}
+
+/// The configuration of a duplex sponge is the IV, since the parameters of the permutation
+/// are fixed by the type.
+impl<S, C, const N: usize> CryptographicSponge for DuplexSponge<S>
+where
+    C: FpConfig<N>,
+    S: Sponge<U = Fp<C, N>>,
+{
+    type Config = [u8; 32];
+
+    fn new(iv: &Self::Config) -> Self {
+        DuplexHash::new(*iv)
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        self.absorb_unchecked(&input.to_sponge_field_elements_as_vec());
+    }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        squeeze_bytes_from_elements(num_bytes, |n| squeeze_sponge(self, n))
+    }
+
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        squeeze_bits_from_elements(num_bits, |n| squeeze_sponge(self, n))
+    }
+}
+
+impl<S, C, const N: usize> FieldBasedCryptographicSponge<Fp<C, N>> for DuplexSponge<S>
+where
+    C: FpConfig<N>,
+    S: Sponge<U = Fp<C, N>>,
+{
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<Fp<C, N>> {
+        squeeze_sponge(self, num_elements)
+    }
+}
+
+/// A [`CryptographicSponge`] with a canonical configuration,
+/// so that it can be instantiated as a [`DuplexHash`] through [`SpongeBridge`].
+pub trait SpongeConfig: CryptographicSponge {
+    /// The configuration used to instantiate the sponge.
+    fn config() -> Self::Config;
+}
+
+/// A bridge to our sponge interface for [`FieldBasedCryptographicSponge`]s,
+/// e.g. [`ark_crypto_primitives::sponge::poseidon::PoseidonSponge`].
+///
+/// The IV is absorbed right after instantiating the sponge.
+/// Ratcheting squeezes one element and absorbs it into a freshly-instantiated sponge.
+#[derive(Clone)]
+pub struct SpongeBridge<S, F>
+where
+    S: FieldBasedCryptographicSponge<F> + SpongeConfig,
+    F: PrimeField,
+{
+    sponge: S,
+    _field: core::marker::PhantomData<F>,
+}
+
+impl<S, F> Default for SpongeBridge<S, F>
+where
+    S: FieldBasedCryptographicSponge<F> + SpongeConfig,
+    F: PrimeField,
+{
+    fn default() -> Self {
+        Self {
+            sponge: S::new(&S::config()),
+            _field: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, F> zeroize::Zeroize for SpongeBridge<S, F>
+where
+    S: FieldBasedCryptographicSponge<F> + SpongeConfig,
+    F: PrimeField,
+{
+    /// The state of a [`CryptographicSponge`] cannot be accessed: we replace it with a fresh one.
+    fn zeroize(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl<S, F> DuplexHash<F> for SpongeBridge<S, F>
+where
+    S: FieldBasedCryptographicSponge<F> + SpongeConfig,
+    F: PrimeField + Unit + Absorb,
+{
+    /// The parameters of `S` are opaque: they are assumed to target 128 bits, as arkworks' Poseidon instances do.
+    const SECURITY_LEVEL: usize = 128;
+
+    fn new(iv: [u8; 32]) -> Self {
+        let mut bridge = Self::default();
+        bridge.sponge.absorb(&F::from_be_bytes_mod_order(&iv));
+        bridge
+    }
+
+    fn absorb_unchecked(&mut self, input: &[F]) -> &mut Self {
+        self.sponge.absorb(&input);
+        self
+    }
+
+    fn squeeze_unchecked(&mut self, output: &mut [F]) -> &mut Self {
+        let elements = self.sponge.squeeze_native_field_elements(output.len());
+        output.copy_from_slice(&elements);
+        self
+    }
+
+    fn ratchet_unchecked(&mut self) -> &mut Self {
+        let [element] = self.sponge.squeeze_native_field_elements(1)[..] else {
+            unreachable!()
+        };
+        *self = Self::default();
+        self.sponge.absorb(&element);
+        self
+    }
+}
@@ -1,6 +1,10 @@
 #[cfg(feature = "ark-bls12-381")]
+use super::griffin::bls12_381::GriffinBls12381Fr3_2;
+#[cfg(feature = "ark-bls12-381")]
 use super::poseidon::PoseidonHash;
-use crate::{DefaultHash, DuplexHash, IOPattern, Unit, UnitTranscript};
+use super::rescue::f64::{Rp64_256, F64};
+use super::{FieldChallenges, FieldReader, FieldWriter};
+use crate::{Arthur, DefaultHash, DuplexHash, IOPattern, Merlin, Unit, UnitTranscript};
 #[cfg(feature = "ark-bls12-381")]
 use ark_bls12_381::Fr;
 
@@ -19,8 +23,11 @@ fn check_iv_is_used<H: DuplexHash<F>, F: Unit + Copy + Default + Eq + core::fmt:
 #[test]
 fn test_iv_is_used() {
     check_iv_is_used::<DefaultHash, u8>();
+    check_iv_is_used::<Rp64_256, F64>();
     #[cfg(feature = "ark-bls12-381")]
     check_iv_is_used::<PoseidonHash<Fr, 2, 3>, Fr>();
+    #[cfg(feature = "ark-bls12-381")]
+    check_iv_is_used::<GriffinBls12381Fr3_2, Fr>();
 }
 
 /// Check that poseidon can indeed be instantiated and doesn't do terribly stupid things like give 0 challenges.
@@ -43,3 +50,959 @@ fn test_poseidon_basic() {
         assert_ne!(challenge, F::from(0));
     }
 }
+
+/// Check that scalars written by the prover are read back by the verifier, and that both derive the same challenges.
+fn check_field_transcript<H, F>()
+where
+    H: DuplexHash<F>,
+    F: ark_ff::PrimeField + Unit,
+    IOPattern<H, F>: super::FieldIOPattern<F>,
+    Merlin<H, F>: FieldWriter<F> + FieldChallenges<F>,
+    for<'a> Arthur<'a, H, F>: FieldReader<F> + FieldChallenges<F>,
+{
+    check_transcript::<H, F, F>()
+}
+
+/// Check that elements of `F` written by the prover are read back by the verifier, and that both derive the same challenges,
+/// over a sponge with units `U`.
+fn check_transcript<H, U, F>()
+where
+    H: DuplexHash<U>,
+    U: Unit,
+    F: ark_ff::Field,
+    IOPattern<H, U>: super::FieldIOPattern<F>,
+    Merlin<H, U>: FieldWriter<F> + FieldChallenges<F>,
+    for<'a> Arthur<'a, H, U>: FieldReader<F> + FieldChallenges<F>,
+{
+    use super::FieldIOPattern;
+
+    let io = IOPattern::<H, U>::new("test")
+        .add_scalars(20, "in")
+        .challenge_scalars(3, "out");
+    let mut rng = ark_std::test_rng();
+    let scalars = (0..20)
+        .map(|_| ark_std::UniformRand::rand(&mut rng))
+        .collect::<Vec<F>>();
+
+    let mut merlin = io.to_merlin();
+    merlin.add_scalars(&scalars).unwrap();
+    let merlin_challenges: [F; 3] = merlin.challenge_scalars().unwrap();
+
+    let mut arthur = io.to_arthur(merlin.transcript());
+    let mut read = vec![F::zero(); 20];
+    arthur.fill_next_scalars(&mut read).unwrap();
+    let arthur_challenges: [F; 3] = arthur.challenge_scalars().unwrap();
+
+    assert_eq!(read, scalars);
+    assert_eq!(merlin_challenges, arthur_challenges);
+    assert_ne!(merlin_challenges[0], merlin_challenges[1]);
+}
+
+#[test]
+fn test_rescue_transcript() {
+    check_field_transcript::<Rp64_256, F64>();
+}
+
+/// Algebraic sponges are as secure as half their capacity, up to the 128 bits targeted by their rounds.
+#[test]
+fn test_algebraic_security_level() {
+    assert_eq!(Rp64_256::SECURITY_LEVEL, 128);
+    #[cfg(feature = "ark-bls12-381")]
+    assert_eq!(GriffinBls12381Fr3_2::SECURITY_LEVEL, 127);
+}
+
+/// The Rescue-Prime instance over F64 uses the tables of Winterfell's `Rp64_256`.
+#[test]
+fn test_rescue_rp64_256_permutation() {
+    use super::rescue::RescueState;
+    use crate::hash::sponge::Sponge;
+
+    let mut rescue = RescueState::<F64, 8, 12>::default();
+    let mds_row: Vec<F64> = [7u64, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8]
+        .map(F64::from)
+        .to_vec();
+    assert_eq!(rescue.mds[0].to_vec(), mds_row);
+    assert_eq!(rescue.mds[1][0], F64::from(8u64));
+    assert_eq!(rescue.ark1[0][0], F64::from(5789762306288267392u64));
+    assert_eq!(rescue.ark2[0][0], F64::from(6077062762357204287u64));
+
+    // Permutation of [0, 1, ..., 11], cf. `scripts/rescue_prime_constants.py --test-vector`.
+    rescue.state = core::array::from_fn(|i| F64::from(i as u64));
+    rescue.permute();
+    let expected = [
+        5239082800414925025u64,
+        9544264338261706097,
+        12713203177939406742,
+        8814723979809065654,
+        12516427669998607935,
+        3109607101026390618,
+        6635370625872995724,
+        5874030392380953747,
+        1035525977590874036,
+        17758658414792619149,
+        13515650209313774766,
+        3592607325016591320,
+    ]
+    .map(F64::from);
+    assert_eq!(rescue.state, expected);
+}
+
+#[test]
+#[cfg(feature = "ark-bls12-381")]
+fn test_griffin_transcript() {
+    check_field_transcript::<GriffinBls12381Fr3_2, Fr>();
+}
+
+/// Griffin on widths larger than 3, against `scripts/griffin_constants.py --test-vector`.
+#[test]
+#[cfg(feature = "ark-bls12-381")]
+fn test_griffin_wide_permutation() {
+    use super::griffin::GriffinState;
+    use ark_ff::MontFp;
+
+    fn permute<const N: usize>() -> [Fr; N] {
+        // A toy instance with 3 rounds, round constants r N + i + 1, and (alpha, beta) = (2, 3).
+        let round_constants: Vec<[Fr; N]> = (0..2)
+            .map(|r| core::array::from_fn(|i| Fr::from((r * N + i + 1) as u64)))
+            .collect();
+        let mut griffin = GriffinState::<Fr, 2, N> {
+            rounds: 3,
+            d: 5,
+            d_inv: GriffinState::<Fr, 2, 3>::default().d_inv,
+            alpha_beta: [Fr::from(2u64), Fr::from(3u64)],
+            round_constants: Vec::leak(round_constants),
+            state: core::array::from_fn(|i| Fr::from((i + 1) as u64)),
+        };
+        griffin.apply_permutation();
+        griffin.state
+    }
+
+    let expected: [Fr; 4] = [
+        MontFp!("17291151185421575143998888670312156341804541798283603372192974684132994971003"),
+        MontFp!("27836490668408228398740284457744092122270990906367997213242573526785931763960"),
+        MontFp!("46465857471707712950558732035296565779826725803213781474613686299948723746954"),
+        MontFp!("36288490260458576803343754353481221606646049495360796103104561267337547262565"),
+    ];
+    assert_eq!(permute::<4>(), expected);
+
+    let expected: [Fr; 8] = [
+        MontFp!("6146161536590158862665006704702055979011190843563008343137163360693206212355"),
+        MontFp!("38413684819899402249888971934410597749325226751383378355081053166160403635547"),
+        MontFp!("14754068034138201354005367143565832073683448510947312650227538913527409470182"),
+        MontFp!("26456049158502280179631058166129163678323199662558377207308821629519413704723"),
+        MontFp!("22916211459569643488472925865993504853602134677227684798405335570821227860772"),
+        MontFp!("29997892981588312906698133237545053776682452211422634051951298876154454086092"),
+        MontFp!("8802134935438359389304140866783357065085101035808493655700126329270771560349"),
+        MontFp!("33438728674869721808224715920184133263519174362149460524019988912311326488141"),
+    ];
+    assert_eq!(permute::<8>(), expected);
+}
+
+/// Short scalars are squeezed as bytes, both from byte-oriented and algebraic transcripts.
+fn check_short_scalars<H, U>()
+where
+    U: Unit,
+    H: DuplexHash<U>,
+    IOPattern<H, U>: super::FieldIOPattern<F64> + crate::ByteIOPattern,
+    Merlin<H, U>: FieldChallenges<F64> + crate::ByteChallenges,
+    for<'a> Arthur<'a, H, U>: FieldChallenges<F64> + crate::ByteChallenges,
+{
+    use super::FieldIOPattern;
+    use crate::{ByteChallenges, ByteIOPattern};
+    use ark_ff::PrimeField;
+
+    let io = IOPattern::<H, U>::new("short")
+        .challenge_short_scalars(10, 32, "short")
+        .challenge_bits(20, "bits");
+    let mut merlin = io.to_merlin();
+    let short: [F64; 10] = merlin.challenge_short_scalars(32).unwrap();
+    let bits = merlin.challenge_bits(20).unwrap();
+    let short_u64 = short.map(|x| x.into_bigint().0[0]);
+    assert!(short_u64.iter().all(|&x| x < 1 << 32));
+    assert!(short_u64.iter().any(|&x| x >= 1 << 31));
+    // Too many bits for the field.
+    assert!(merlin.challenge_short_scalars::<1>(64).is_err());
+
+    let mut arthur = io.to_arthur(merlin.transcript());
+    assert_eq!(arthur.challenge_short_scalars::<10>(32).unwrap(), short);
+    assert_eq!(arthur.challenge_bits(20).unwrap(), bits);
+}
+
+#[test]
+fn test_short_scalars() {
+    check_short_scalars::<DefaultHash, u8>();
+    check_short_scalars::<Rp64_256, F64>();
+}
+
+/// Challenge bytes are the least significant bytes of $\sum_i x_i p^i$ for the squeezed units $x_i$.
+#[test]
+fn test_challenge_bytes_algebraic_hash() {
+    use crate::{ByteChallenges, ByteIOPattern};
+    use ark_ff::PrimeField;
+
+    // 8 bytes are taken from (64 + 128) / 63 = 4 units of F64.
+    let io = IOPattern::<Rp64_256, F64>::new("bytes").challenge_bytes(8, "bytes");
+    assert_eq!(io.as_bytes(), b"bytes\0S4bytes");
+    let bytes: [u8; 8] = io.to_arthur(&[]).challenge_bytes().unwrap();
+
+    let mut units = [F64::from(0u64); 4];
+    io.to_arthur(&[]).fill_challenge_units(&mut units).unwrap();
+    let p = F64::MODULUS.0[0];
+    let expected = units.iter().rev().fold(0u64, |acc, x| {
+        acc.wrapping_mul(p).wrapping_add(x.into_bigint().0[0])
+    });
+    assert_eq!(bytes, expected.to_le_bytes());
+}
+
+/// Indices can be sampled from algebraic transcripts, drawing several field elements per index.
+#[test]
+fn test_challenge_indices_algebraic_hash() {
+    use crate::{ByteChallenges, ByteIOPattern};
+
+    let io = IOPattern::<Rp64_256, F64>::new("queries")
+        .challenge_indices(20, 1000, "queries")
+        .challenge_indices(5, 5, "permutation");
+    let mut merlin = io.to_merlin();
+    let queries = merlin.challenge_indices(20, 1000, false).unwrap();
+    let mut permutation = merlin.challenge_indices(5, 5, true).unwrap();
+    assert!(queries.iter().all(|&i| i < 1000));
+    assert!(queries.iter().any(|&i| i != queries[0]));
+    permutation.sort();
+    assert_eq!(permutation, [0, 1, 2, 3, 4]);
+
+    let mut arthur = io.to_arthur(merlin.transcript());
+    assert_eq!(arthur.challenge_indices(20, 1000, false).unwrap(), queries);
+}
+
+/// Extensions of [`F64`] of degree 2, 3, and 4.
+mod f64_extensions {
+    use ark_ff::{
+        CubicExtField, Fp2, Fp2Config, Fp3, Fp3Config, Fp4, Fp4Config, MontFp, QuadExtField,
+    };
+
+    use super::F64;
+
+    pub struct F64_2Config;
+    impl Fp2Config for F64_2Config {
+        type Fp = F64;
+        const NONRESIDUE: F64 = MontFp!("7");
+        const FROBENIUS_COEFF_FP2_C1: &'static [F64] = &[MontFp!("1"), MontFp!("-1")];
+    }
+    pub type F64_2 = Fp2<F64_2Config>;
+
+    pub struct F64_3Config;
+    impl Fp3Config for F64_3Config {
+        type Fp = F64;
+        const NONRESIDUE: F64 = MontFp!("7");
+        const FROBENIUS_COEFF_FP3_C1: &'static [F64] = &[
+            MontFp!("1"),
+            MontFp!("18446744065119617025"),
+            MontFp!("4294967295"),
+        ];
+        const FROBENIUS_COEFF_FP3_C2: &'static [F64] = &[
+            MontFp!("1"),
+            MontFp!("4294967295"),
+            MontFp!("18446744065119617025"),
+        ];
+        const TWO_ADICITY: u32 = 32;
+        const TRACE_MINUS_ONE_DIV_TWO: &'static [u64] =
+            &[9223372049739677694, 9223372049739677692, 2147483646];
+        const QUADRATIC_NONRESIDUE_TO_T: F64_3 =
+            CubicExtField::new(MontFp!("3607031617444012685"), MontFp!("0"), MontFp!("0"));
+    }
+    pub type F64_3 = Fp3<F64_3Config>;
+
+    pub struct F64_4Config;
+    impl Fp4Config for F64_4Config {
+        type Fp2Config = F64_2Config;
+        const NONRESIDUE: F64_2 = QuadExtField::new(MontFp!("0"), MontFp!("1"));
+        const FROBENIUS_COEFF_FP4_C1: &'static [F64] = &[
+            MontFp!("1"),
+            MontFp!("281474976710656"),
+            MontFp!("18446744069414584320"),
+            MontFp!("18446462594437873665"),
+        ];
+    }
+    pub type F64_4 = Fp4<F64_4Config>;
+}
+
+/// Elements of extension fields are absorbed as their coefficients over the base field,
+/// or as bytes over byte-oriented sponges, and squeezed coefficient by coefficient from bytes.
+#[test]
+fn test_extension_transcript() {
+    use super::FieldIOPattern;
+    use ark_ff::Field;
+    use f64_extensions::{F64_2, F64_3, F64_4};
+
+    check_transcript::<Rp64_256, F64, F64_2>();
+    check_transcript::<Rp64_256, F64, F64_3>();
+    check_transcript::<Rp64_256, F64, F64_4>();
+    check_transcript::<DefaultHash, u8, F64_2>();
+    check_transcript::<DefaultHash, u8, F64_3>();
+    check_transcript::<DefaultHash, u8, F64_4>();
+    #[cfg(feature = "ark-bls12-381")]
+    check_transcript::<DefaultHash, u8, ark_bls12_381::Fq12>();
+
+    // Extension elements take as many absorbed units as their degree,
+    // and each of their coefficients is squeezed from 24 bytes, i.e. 6 units.
+    let io: IOPattern<Rp64_256, F64> = FieldIOPattern::<F64_4>::challenge_scalars(
+        FieldIOPattern::<F64_4>::add_scalars(IOPattern::new("ext"), 2, "in"),
+        1,
+        "out",
+    );
+    assert_eq!(io.as_bytes(), b"ext\0A8in\0S24out");
+    let mut merlin = io.to_merlin();
+    merlin
+        .add_scalars(&[F64_4::from(1u64), F64_4::from(2u64)])
+        .unwrap();
+    let [challenge]: [F64_4; 1] = merlin.challenge_scalars().unwrap();
+    assert!(challenge
+        .to_base_prime_field_elements()
+        .all(|c| c != F64::from(0u64)));
+}
+
+/// Elements of the three pairing groups are compressed in the transcript and validated when read back.
+#[test]
+#[cfg(feature = "ark-bls12-381")]
+fn test_pairing_transcript() {
+    use super::{FieldIOPattern, PairingIOPattern, PairingReader, PairingWriter};
+    use ark_bls12_381::{Bls12_381, G1Projective, G2Projective};
+    use ark_ec::pairing::Pairing;
+    use ark_ec::PrimeGroup;
+    use ark_ff::UniformRand;
+
+    let io = IOPattern::<DefaultHash>::new("pairing");
+    let io = PairingIOPattern::<Bls12_381>::add_g1_points(io, 1, "g1");
+    let io = PairingIOPattern::<Bls12_381>::add_g2_points(io, 1, "g2");
+    let io = PairingIOPattern::<Bls12_381>::add_gt_elements(io, 1, "gt");
+    let io = FieldIOPattern::<Fr>::challenge_scalars(io, 1, "chal");
+    assert_eq!(io.as_bytes(), b"pairing\0A48g1\0A96g2\0A576gt\0S47chal");
+
+    let mut rng = ark_std::test_rng();
+    let g1 = G1Projective::generator() * Fr::rand(&mut rng);
+    let g2 = G2Projective::generator() * Fr::rand(&mut rng);
+    let gt = Bls12_381::pairing(g1, g2);
+
+    let mut merlin = io.to_merlin();
+    PairingWriter::<Bls12_381>::add_g1_points(&mut merlin, &[g1]).unwrap();
+    PairingWriter::<Bls12_381>::add_g2_points(&mut merlin, &[g2]).unwrap();
+    merlin.add_gt_elements(&[gt]).unwrap();
+    let [challenge]: [Fr; 1] = merlin.challenge_scalars().unwrap();
+    let transcript = merlin.transcript().to_vec();
+
+    let mut arthur = io.to_arthur(&transcript);
+    let [g1_read] = PairingReader::<Bls12_381>::next_g1_points::<1>(&mut arthur).unwrap();
+    let [g2_read] = PairingReader::<Bls12_381>::next_g2_points::<1>(&mut arthur).unwrap();
+    let [gt_read] = arthur.next_gt_elements::<1>().unwrap();
+    assert_eq!((g1_read, g2_read, gt_read), (g1, g2, gt));
+    let [challenge_read]: [Fr; 1] = arthur.challenge_scalars().unwrap();
+    assert_eq!(challenge_read, challenge);
+
+    // A target group element outside of the subgroup is rejected.
+    let mut tampered = transcript.clone();
+    tampered[48 + 96] ^= 1;
+    let mut arthur = io.to_arthur(&tampered);
+    PairingReader::<Bls12_381>::fill_next_g1_points(&mut arthur, &mut [g1]).unwrap();
+    PairingReader::<Bls12_381>::fill_next_g2_points(&mut arthur, &mut [g2]).unwrap();
+    assert!(PairingReader::<Bls12_381>::next_gt_elements::<1>(&mut arthur).is_err());
+}
+
+/// Points are written and read back with the encoding of the IO pattern.
+fn check_point_encodings<G: super::EncodableCurve>(sizes: [usize; 3]) {
+    use super::{GroupIOPattern, GroupReader, GroupWriter, PointEncoding};
+
+    let mut rng = ark_std::test_rng();
+    let points = [G::rand(&mut rng), G::rand(&mut rng)];
+    let io = |encoding| {
+        let io = IOPattern::<DefaultHash>::new("encoding").with_point_encoding(encoding);
+        let io = GroupIOPattern::<G>::add_points(io, 2, "p");
+        GroupIOPattern::<G>::add_points(io, 1, "q")
+    };
+    let encodings = [
+        PointEncoding::Compressed,
+        PointEncoding::Uncompressed,
+        PointEncoding::XOnly,
+    ];
+    for (encoding, size) in encodings.into_iter().zip(sizes) {
+        assert_eq!(encoding.size::<G>(), size);
+        let io = io(encoding);
+        let mut merlin = io.to_merlin();
+        merlin.add_points(&points).unwrap();
+        // The identity cannot be encoded with a single coordinate.
+        let identity = merlin.add_points(&[G::default()]);
+        assert_eq!(identity.is_err(), encoding == PointEncoding::XOnly);
+        assert_eq!(
+            merlin.transcript().len(),
+            2 * size + identity.map_or(0, |()| size)
+        );
+
+        let mut arthur = io.to_arthur(merlin.transcript());
+        let read: [G; 2] = arthur.next_points().unwrap();
+        for (read, point) in read.iter().zip(&points) {
+            match encoding {
+                PointEncoding::XOnly => assert!(read == point || *read == -*point),
+                _ => assert_eq!(read, point),
+            }
+        }
+    }
+
+    // The encoding is bound to the IO pattern, even for encodings of the same size.
+    assert_eq!(
+        io(PointEncoding::Compressed).as_bytes(),
+        format!("encoding\0A{}p\0A{}q", 2 * sizes[0], sizes[0]).as_bytes()
+    );
+    assert_eq!(
+        io(PointEncoding::XOnly).as_bytes(),
+        format!(
+            "encoding\0A{0}x-only:p\0A{1}x-only:q",
+            2 * sizes[2],
+            sizes[2]
+        )
+        .as_bytes()
+    );
+
+    // A transcript written with one encoding is not accepted by a verifier expecting another one.
+    let mut merlin = io(PointEncoding::Compressed).to_merlin();
+    merlin.add_points(&points).unwrap();
+    merlin.add_points(&points[..1]).unwrap();
+    let xonly = io(PointEncoding::XOnly);
+    let mut arthur = xonly.to_arthur(merlin.transcript());
+    let read = GroupReader::<G>::next_points::<2>(&mut arthur);
+    // Either the bytes do not decode, or they decode to different points.
+    assert!(read.map_or(true, |read| read[0] != points[0] && read[0] != -points[0]));
+}
+
+#[test]
+#[cfg(feature = "ark-bls12-381")]
+fn test_point_encodings() {
+    use super::PointEncoding;
+    use ark_serialize::CanonicalSerialize;
+
+    check_point_encodings::<ark_bls12_381::G1Projective>([48, 96, 48]);
+    check_point_encodings::<ark_bls12_381::G2Projective>([96, 192, 96]);
+
+    // An x-coordinate that is not on the curve is rejected.
+    let x = (1u64..)
+        .map(ark_bls12_381::Fq::from)
+        .find(|&x| ark_bls12_381::G1Affine::get_point_from_x_unchecked(x, false).is_none())
+        .unwrap();
+    let mut bytes = Vec::new();
+    x.serialize_compressed(&mut bytes).unwrap();
+    assert!(PointEncoding::XOnly
+        .decode::<ark_bls12_381::G1Projective>(&bytes)
+        .is_err());
+}
+
+/// Points are absorbed natively over the base field with a tag, and the identity does not need special care.
+#[cfg(feature = "ark-bls12-381")]
+mod algebraic_points {
+    use std::sync::OnceLock;
+
+    use ark_bls12_381::{Fq, G1Projective as G};
+    use ark_ec::PrimeGroup;
+    use ark_ff::{Field, UniformRand};
+
+    use super::super::poseidon::{PoseidonHash, PoseidonSponge};
+    use super::super::{AlgebraicPoint, FieldChallenges, FieldIOPattern, GroupIOPattern};
+    use super::super::{GroupReader, GroupWriter};
+    use crate::IOPattern;
+
+    type H = PoseidonHash<Fq, 2, 3>;
+
+    /// Toy parameters: random round constants and a Cauchy matrix.
+    impl Default for PoseidonSponge<Fq, 2, 3> {
+        fn default() -> Self {
+            static ARK: OnceLock<Vec<[Fq; 3]>> = OnceLock::new();
+            static MDS: OnceLock<Vec<[Fq; 3]>> = OnceLock::new();
+            let mut rng = ark_std::test_rng();
+            let ark = ARK.get_or_init(|| {
+                (0..64)
+                    .map(|_| [(); 3].map(|_| Fq::rand(&mut rng)))
+                    .collect()
+            });
+            let mds = MDS.get_or_init(|| {
+                (0..3u64)
+                    .map(|i| [0, 1, 2].map(|j| Fq::from(i + j + 3).inverse().unwrap()))
+                    .collect()
+            });
+            Self {
+                full_rounds: 8,
+                partial_rounds: 56,
+                alpha: 5,
+                ark,
+                mds,
+                state: [Fq::from(0); 3],
+            }
+        }
+    }
+
+    #[test]
+    fn test_algebraic_points() {
+        let generator = G::generator();
+        let identity = G::default();
+        let zero = Fq::from(0);
+        assert_eq!(identity.to_algebraic_units(), [Fq::from(3), zero, zero]);
+        assert_eq!(generator.to_algebraic_units()[0], Fq::from(2));
+
+        // BLS12-381 G1 is y^2 = x^3 + 4.
+        let io = IOPattern::<H, Fq>::new("points");
+        let io = GroupIOPattern::<G>::add_points(io, 3, "points");
+        let io = FieldIOPattern::<Fq>::challenge_scalars(io, 1, "chal");
+        assert_eq!(io.as_bytes(), b"points\0A9sw(0,4):points\0S2chal");
+
+        let points = [generator, identity, -generator];
+        let mut merlin = io.to_merlin();
+        merlin.add_points(&points).unwrap();
+        let [challenge]: [Fq; 1] = merlin.challenge_scalars().unwrap();
+
+        let mut arthur = io.to_arthur(merlin.transcript());
+        let read: [G; 3] = arthur.next_points().unwrap();
+        assert_eq!(read, points);
+        let [challenge_read]: [Fq; 1] = arthur.challenge_scalars().unwrap();
+        assert_eq!(challenge_read, challenge);
+    }
+}
+
+/// Elements of the base field of BLS12-381 are sent as two limbs over the scalar field, and read back.
+#[test]
+#[cfg(feature = "ark-bls12-381")]
+fn test_non_native_scalars() {
+    use super::poseidon::bls12_381::PoseidonBls12381Fr3_1 as H;
+    use super::{
+        NonNativeFieldChallenges, NonNativeFieldIOPattern, NonNativeFieldReader,
+        NonNativeFieldWriter,
+    };
+    use ark_bls12_381::Fq;
+    use ark_ff::{BigInteger, PrimeField};
+
+    // Limbs recompose to the canonical representative.
+    let x = -Fq::from(1);
+    let limbs = super::common::to_non_native_limbs::<Fq, Fr>(&x);
+    assert_eq!(limbs.len(), 2);
+    let mut bits = limbs[0].into_bigint().to_bits_le()[..254].to_vec();
+    bits.extend(limbs[1].into_bigint().to_bits_le());
+    assert_eq!(
+        Fq::from_bigint(<Fq as PrimeField>::BigInt::from_bits_le(&bits[..381])),
+        Some(x)
+    );
+
+    let io = IOPattern::<H, Fr>::new("non-native");
+    let io = NonNativeFieldIOPattern::<Fq>::add_non_native_scalars(io, 2, "scalars");
+    let io = NonNativeFieldIOPattern::<Fq>::challenge_non_native_scalars(io, 2, "chal");
+    assert_eq!(io.as_bytes(), b"non-native\0A4scalars\0S5chal");
+
+    let scalars = [x, Fq::from(5)];
+    let mut merlin = io.to_merlin();
+    merlin.add_non_native_scalars(&scalars).unwrap();
+    let challenges: [Fq; 2] = merlin.challenge_non_native_scalars().unwrap();
+    assert_ne!(challenges[0], challenges[1]);
+    let transcript = merlin.transcript().to_vec();
+    assert_eq!(transcript.len(), 2 * 48);
+
+    let mut arthur = io.to_arthur(&transcript);
+    let scalars_read: [Fq; 2] = arthur.next_non_native_scalars().unwrap();
+    assert_eq!(scalars_read, scalars);
+    let challenges_read: [Fq; 2] = arthur.challenge_non_native_scalars().unwrap();
+    assert_eq!(challenges_read, challenges);
+
+    // Non-canonical encodings are rejected.
+    let mut tampered = transcript;
+    tampered[..48].fill(0xff);
+    let mut arthur = io.to_arthur(&tampered);
+    assert!(NonNativeFieldReader::<Fq>::next_non_native_scalars::<1>(&mut arthur).is_err());
+}
+
+/// On twisted Edwards curves, the single coordinate is the Montgomery $u$-coordinate, as in X25519.
+#[test]
+fn test_point_encodings_edwards() {
+    use super::{EncodableCurve, PointEncoding};
+    use ark_ec::PrimeGroup;
+
+    type G = ark_curve25519::EdwardsProjective;
+    check_point_encodings::<G>([32, 64, 32]);
+
+    let mut base_point = [0u8; 32];
+    base_point[0] = 9;
+    let mut bytes = Vec::new();
+    PointEncoding::XOnly
+        .encode(&G::generator(), &mut bytes)
+        .unwrap();
+    assert_eq!(bytes, base_point);
+    let decoded = G::from_x_only(G::generator().x_only().unwrap()).unwrap();
+    assert!(decoded == G::generator() || decoded == -G::generator());
+}
+
+/// Algebraic sponges can be checkpointed as well.
+#[test]
+fn test_export_import_algebraic_hash() {
+    let mut hash = Rp64_256::new([0x42; 32]);
+    hash.absorb_unchecked(&[F64::from(1u64), F64::from(2u64)]);
+    let mut resumed = Rp64_256::import_state(&hash.export_state().unwrap()).unwrap();
+
+    let mut expected = [F64::from(0u64); 10];
+    let mut got = [F64::from(0u64); 10];
+    hash.squeeze_unchecked(&mut expected);
+    resumed.squeeze_unchecked(&mut got);
+    assert_eq!(expected, got);
+}
+
+#[cfg(all(feature = "ark-crypto-primitives", feature = "ark-bls12-381"))]
+mod sponge {
+    use ark_bls12_381::Fr;
+    use ark_crypto_primitives::sponge::poseidon::{
+        find_poseidon_ark_and_mds, PoseidonConfig, PoseidonSponge,
+    };
+    use ark_crypto_primitives::sponge::{CryptographicSponge, FieldBasedCryptographicSponge};
+    use ark_ff::PrimeField;
+
+    use super::super::poseidon::PoseidonHash;
+    use super::super::sponge::{SpongeBridge, SpongeConfig};
+    use super::check_field_transcript;
+    use crate::{Arthur, DuplexHash, IOPattern, Merlin};
+
+    type H = PoseidonHash<Fr, 2, 3>;
+
+    /// Merlin and Arthur, used as [`CryptographicSponge`]s, agree on the challenges.
+    #[test]
+    fn test_merlin_arthur_as_cryptographic_sponge() {
+        let io = IOPattern::<H, Fr>::new("test")
+            .absorb(2, "public")
+            .squeeze(3, "challenges")
+            .squeeze(2, "bytes");
+
+        let mut merlin = <Merlin<H, Fr> as CryptographicSponge>::new(&io);
+        merlin.absorb(&vec![Fr::from(1u64), Fr::from(2u64)]);
+        let merlin_challenges = merlin.squeeze_native_field_elements(3);
+        let merlin_bytes = merlin.squeeze_bytes(40);
+
+        let config = (io.clone(), merlin.transcript());
+        let mut arthur = <Arthur<H, Fr> as CryptographicSponge>::new(&config);
+        arthur.absorb(&vec![Fr::from(1u64), Fr::from(2u64)]);
+        assert_eq!(arthur.squeeze_native_field_elements(3), merlin_challenges);
+        assert_eq!(arthur.squeeze_bytes(40), merlin_bytes);
+    }
+
+    /// Duplex sponges behave the same through both interfaces.
+    #[test]
+    fn test_duplex_sponge_as_cryptographic_sponge() {
+        let mut sponge = <H as CryptographicSponge>::new(&[0x42; 32]);
+        sponge.absorb(&Fr::from(7u64));
+        let got = sponge.squeeze_native_field_elements(5);
+
+        let mut expected = [Fr::from(0u64); 5];
+        <H as DuplexHash<Fr>>::new([0x42; 32])
+            .absorb_unchecked(&[Fr::from(7u64)])
+            .squeeze_unchecked(&mut expected);
+        assert_eq!(got, expected);
+    }
+
+    impl SpongeConfig for PoseidonSponge<Fr> {
+        fn config() -> PoseidonConfig<Fr> {
+            let (ark, mds) =
+                find_poseidon_ark_and_mds::<Fr>(Fr::MODULUS_BIT_SIZE as u64, 2, 8, 57, 0);
+            PoseidonConfig::new(8, 57, 5, mds, ark, 2, 1)
+        }
+    }
+
+    /// Any [`CryptographicSponge`] can be used to build transcripts.
+    #[test]
+    fn test_sponge_bridge_transcript() {
+        check_field_transcript::<SpongeBridge<PoseidonSponge<Fr>, Fr>, Fr>();
+    }
+}
+
+/// The in-circuit verifier derives the same challenges as the native one.
+#[test]
+#[cfg(all(feature = "ark-r1cs-std", feature = "ark-bls12-381"))]
+fn test_arthur_var() {
+    use super::poseidon::PoseidonSponge;
+    use super::r1cs::ArthurVar;
+    use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    type H = PoseidonHash<Fr, 2, 3>;
+    let io = IOPattern::<H, Fr>::new("recursion")
+        .absorb(3, "commitment")
+        .squeeze(2, "challenges")
+        .ratchet()
+        .absorb(1, "response")
+        .squeeze(3, "final challenges");
+
+    let mut merlin = io.to_merlin();
+    let mut challenges = vec![Fr::from(0u64); 5];
+    merlin
+        .add_units(&[Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)])
+        .unwrap();
+    merlin.fill_challenge_units(&mut challenges[..2]).unwrap();
+    merlin.ratchet().unwrap();
+    merlin.add_units(&[Fr::from(4u64)]).unwrap();
+    merlin.fill_challenge_units(&mut challenges[2..]).unwrap();
+
+    let cs = ConstraintSystem::<Fr>::new_ref();
+    let transcript = (1..=4u64)
+        .map(|i| FpVar::new_witness(cs.clone(), || Ok(Fr::from(i))).unwrap())
+        .collect();
+    let mut arthur = ArthurVar::new(&io, transcript, &PoseidonSponge::default());
+    arthur.next_units(3).unwrap();
+    let mut got = arthur.challenge_units(2).unwrap();
+    arthur.ratchet().unwrap();
+    arthur.next_units(1).unwrap();
+    got.extend(arthur.challenge_units(3).unwrap());
+
+    assert_eq!(got.value().unwrap(), challenges);
+    assert!(cs.is_satisfied().unwrap());
+    // Deviating from the IO Pattern is an error.
+    assert!(arthur.challenge_units(1).is_err());
+}
+
+/// The halo2 chip derives the same challenges as the native verifier, over the Pallas base field.
+#[cfg(feature = "halo2")]
+mod halo2 {
+    use std::marker::PhantomData;
+    use std::sync::OnceLock;
+
+    use ark_ff::{Field, UniformRand};
+    use ark_pallas::Fq;
+    use halo2_proofs::circuit::{Layouter, SimpleFloorPlanner};
+    use halo2_proofs::dev::MockProver;
+    use halo2_proofs::pasta::Fp;
+    use halo2_proofs::plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Instance};
+
+    use super::super::halo2::{PoseidonChip, PoseidonConfig, PoseidonParameters, SafeChip};
+    use super::super::poseidon::{PoseidonHash, PoseidonSponge};
+    use crate::hash::Unit;
+    use crate::{DuplexHash, IOPattern, UnitTranscript};
+
+    /// Toy parameters: random round constants and a Cauchy matrix.
+    impl Default for PoseidonSponge<Fq, 2, 3> {
+        fn default() -> Self {
+            static ARK: OnceLock<Vec<[Fq; 3]>> = OnceLock::new();
+            static MDS: OnceLock<Vec<[Fq; 3]>> = OnceLock::new();
+            let mut rng = ark_std::test_rng();
+            let ark = ARK.get_or_init(|| {
+                (0..64)
+                    .map(|_| [(); 3].map(|_| Fq::rand(&mut rng)))
+                    .collect()
+            });
+            let mds = MDS.get_or_init(|| {
+                (0..3u64)
+                    .map(|i| [0, 1, 2].map(|j| Fq::from(i + j + 3).inverse().unwrap()))
+                    .collect()
+            });
+            Self {
+                full_rounds: 8,
+                partial_rounds: 56,
+                alpha: 5,
+                ark,
+                mds,
+                state: [Fq::from(0); 3],
+            }
+        }
+    }
+
+    /// The native sponge of a transcript, and its parameters in the circuit.
+    trait Setup {
+        type U: Unit;
+        type H: DuplexHash<Self::U>;
+
+        fn parameters() -> PoseidonParameters<Fp, 3>;
+        fn to_fp(x: &Self::U) -> Fp;
+    }
+
+    /// The toy parameters over arkworks' [`Fq`].
+    struct Toy;
+
+    impl Setup for Toy {
+        type U = Fq;
+        type H = PoseidonHash<Fq, 2, 3>;
+
+        fn parameters() -> PoseidonParameters<Fp, 3> {
+            (&PoseidonSponge::<Fq, 2, 3>::default()).into()
+        }
+
+        fn to_fp(x: &Fq) -> Fp {
+            use ark_ff::{BigInteger, PrimeField};
+            use halo2_proofs::pasta::group::ff::PrimeField as _;
+
+            let bytes = x.into_bigint().to_bytes_le();
+            Fp::from_repr(bytes.try_into().unwrap()).unwrap()
+        }
+    }
+
+    /// The Orchard parameters over the [`FieldUnit`][`crate::plugins::group::FieldUnit`]s of the `group` plugin.
+    #[cfg(feature = "pasta_curves")]
+    struct Orchard;
+
+    #[cfg(feature = "pasta_curves")]
+    impl Setup for Orchard {
+        type U = crate::plugins::group::FieldUnit<pasta_curves::Fp>;
+        type H = crate::plugins::group::poseidon::pasta::PoseidonPallas;
+
+        fn parameters() -> PoseidonParameters<Fp, 3> {
+            (&crate::plugins::group::poseidon::PoseidonSponge::<pasta_curves::Fp, 2, 3>::default())
+                .into()
+        }
+
+        fn to_fp(x: &Self::U) -> Fp {
+            use halo2_proofs::pasta::group::ff::PrimeField as _;
+            use pasta_curves::group::ff::PrimeField as _;
+
+            Fp::from_repr(x.0.to_repr()).unwrap()
+        }
+    }
+
+    fn io_pattern<S: Setup>() -> IOPattern<S::H, S::U> {
+        IOPattern::new("recursion")
+            .absorb(3, "commitment")
+            .squeeze(2, "challenges")
+            .ratchet()
+            .absorb(1, "response")
+            .squeeze(3, "final challenges")
+    }
+
+    struct TranscriptCircuit<S> {
+        messages: Vec<Fp>,
+        _setup: PhantomData<S>,
+    }
+
+    #[derive(Clone, Debug)]
+    struct TranscriptConfig {
+        poseidon: PoseidonConfig<Fp, 3>,
+        messages: Column<Advice>,
+        challenges: Column<Instance>,
+    }
+
+    impl<S: Setup> Circuit<Fp> for TranscriptCircuit<S> {
+        type Config = TranscriptConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self {
+                messages: Vec::new(),
+                _setup: PhantomData,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> TranscriptConfig {
+            let state = [(); 3].map(|_| meta.advice_column());
+            let round_constants = [(); 3].map(|_| meta.fixed_column());
+            let constants = meta.fixed_column();
+            let messages = meta.advice_column();
+            let challenges = meta.instance_column();
+            meta.enable_equality(messages);
+            meta.enable_equality(challenges);
+            TranscriptConfig {
+                poseidon: PoseidonChip::<Fp, 2, 3>::configure(
+                    meta,
+                    state,
+                    round_constants,
+                    constants,
+                    S::parameters(),
+                ),
+                messages,
+                challenges,
+            }
+        }
+
+        fn synthesize(
+            &self,
+            config: TranscriptConfig,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let messages = layouter.assign_region(
+                || "messages",
+                |mut region| {
+                    (0..4)
+                        .map(|i| {
+                            region.assign_advice(
+                                || "message",
+                                config.messages,
+                                i,
+                                || self.messages.get(i).copied().ok_or(Error::Synthesis),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                },
+            )?;
+            let chip = PoseidonChip::<Fp, 2, 3>::construct(config.poseidon);
+            let mut safe = SafeChip::new(chip, layouter.namespace(|| "init"), &io_pattern::<S>())?;
+            safe.absorb(layouter.namespace(|| "commitment"), &messages[..3])?;
+            let mut challenges = safe.squeeze(layouter.namespace(|| "challenges"), 2)?;
+            safe.ratchet(layouter.namespace(|| "ratchet"))?;
+            safe.absorb(layouter.namespace(|| "response"), &messages[3..])?;
+            challenges.extend(safe.squeeze(layouter.namespace(|| "final challenges"), 3)?);
+            // Deviating from the IO Pattern is an error.
+            assert!(safe.squeeze(layouter.namespace(|| "extra"), 1).is_err());
+
+            for (row, challenge) in challenges.iter().enumerate() {
+                layouter.constrain_instance(challenge.cell(), config.challenges, row)?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Run the transcript natively, and check the challenges in the circuit.
+    fn check_safe_chip<S: Setup>(messages: &[S::U]) {
+        let mut merlin = io_pattern::<S>().to_merlin();
+        let mut challenges = vec![messages[0].clone(); 5];
+        merlin.add_units(&messages[..3]).unwrap();
+        merlin.fill_challenge_units(&mut challenges[..2]).unwrap();
+        merlin.ratchet().unwrap();
+        merlin.add_units(&messages[3..]).unwrap();
+        merlin.fill_challenge_units(&mut challenges[2..]).unwrap();
+
+        let circuit = TranscriptCircuit::<S> {
+            messages: messages.iter().map(S::to_fp).collect(),
+            _setup: PhantomData,
+        };
+        let mut instance = challenges.iter().map(S::to_fp).collect::<Vec<_>>();
+        let prover = MockProver::run(10, &circuit, vec![instance.clone()]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        // Wrong challenges are rejected.
+        instance[4] += Fp::one();
+        let prover = MockProver::run(10, &circuit, vec![instance]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn test_safe_chip() {
+        check_safe_chip::<Toy>(&(1..=4u64).map(Fq::from).collect::<Vec<_>>());
+    }
+
+    /// Transcripts over [`FieldUnit`][`crate::plugins::group::FieldUnit`]s with the Orchard parameters.
+    #[test]
+    #[cfg(feature = "pasta_curves")]
+    fn test_safe_chip_pasta() {
+        use crate::plugins::group::FieldUnit;
+
+        let messages = (1..=4u64)
+            .map(|i| FieldUnit(pasta_curves::Fp::from(i)))
+            .collect::<Vec<_>>();
+        check_safe_chip::<Orchard>(&messages);
+    }
+}
+
+/// Proofs of work can be embedded in algebraic transcripts, with the nonce absorbed as bytes.
+#[test]
+#[cfg(feature = "ark-bls12-381")]
+fn test_pow_algebraic_transcript() {
+    use crate::plugins::pow::{DuplexPoW, PoWChallenge, PoWIOPattern};
+    use crate::plugins::small_fields::{poseidon2::Poseidon2BabyBear, BabyBear};
+    type H = PoseidonHash<Fr, 2, 3>;
+
+    let io = IOPattern::<H, Fr>::new("pow").challenge_pow(8, "work");
+    assert_eq!(io.as_bytes(), b"pow\0A1work-bits\0S2work\0A8work-nonce");
+
+    let mut merlin = io.to_merlin();
+    let expected = merlin
+        .challenge_pow_with::<DuplexPoW<Poseidon2BabyBear, BabyBear>>(8)
+        .unwrap();
+    assert_eq!(merlin.transcript().len(), 8);
+
+    let mut arthur = io.to_arthur(merlin.transcript());
+    let got = arthur
+        .challenge_pow_with::<DuplexPoW<Poseidon2BabyBear, BabyBear>>(8)
+        .unwrap();
+    assert_eq!(expected.0, got.0);
+}
@@ -1,12 +1,19 @@
+use ark_ec::pairing::{Pairing, PairingOutput};
 use ark_ec::CurveGroup;
-use ark_ff::{Fp, FpConfig, PrimeField};
+use ark_ff::{Field, Fp, FpConfig, PrimeField};
 use ark_serialize::CanonicalSerialize;
 use rand::{CryptoRng, RngCore};
 
-use super::{FieldPublic, FieldWriter, GroupPublic, GroupWriter};
-use crate::{DuplexHash, Merlin, ProofResult, UnitTranscript};
+use super::common::to_non_native_limbs;
+use super::{
+    EncodableCurve, FieldPublic, FieldWriter, GroupPublic, GroupWriter, NonNativeFieldWriter,
+    PairingWriter,
+};
+use crate::{
+    BytePublic, ByteWriter, DuplexHash, IOPatternError, Merlin, ProofResult, UnitTranscript,
+};
 
-impl<F: PrimeField, H: DuplexHash, R: RngCore + CryptoRng> FieldWriter<F> for Merlin<H, u8, R> {
+impl<F: Field, H: DuplexHash, R: RngCore + CryptoRng> FieldWriter<F> for Merlin<H, u8, R> {
     fn add_scalars(&mut self, input: &[F]) -> ProofResult<()> {
         let serialized = self.public_scalars(input);
         self.transcript.extend(serialized?);
@@ -14,11 +21,15 @@ impl<F: PrimeField, H: DuplexHash, R: RngCore + CryptoRng> FieldWriter<F> for Me
     }
 }
 
-impl<C: FpConfig<N>, H: DuplexHash<Fp<C, N>>, R: RngCore + CryptoRng, const N: usize>
-    FieldWriter<Fp<C, N>> for Merlin<H, Fp<C, N>, R>
+impl<F, C, H, R, const N: usize> FieldWriter<F> for Merlin<H, Fp<C, N>, R>
+where
+    F: Field<BasePrimeField = Fp<C, N>>,
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+    R: RngCore + CryptoRng,
 {
-    fn add_scalars(&mut self, input: &[Fp<C, N>]) -> ProofResult<()> {
-        self.public_units(input)?;
+    fn add_scalars(&mut self, input: &[F]) -> ProofResult<()> {
+        self.public_scalars(input)?;
         for i in input {
             i.serialize_compressed(&mut self.transcript)?;
         }
@@ -26,9 +37,39 @@ impl<C: FpConfig<N>, H: DuplexHash<Fp<C, N>>, R: RngCore + CryptoRng, const N: u
     }
 }
 
+impl<C, H, R, const N: usize> ByteWriter for Merlin<H, Fp<C, N>, R>
+where
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+    R: RngCore + CryptoRng,
+{
+    fn add_bytes(&mut self, input: &[u8]) -> Result<(), IOPatternError> {
+        self.public_bytes(input)?;
+        self.transcript.extend(input);
+        Ok(())
+    }
+}
+
+impl<F2, C, H, R, const N: usize> NonNativeFieldWriter<F2> for Merlin<H, Fp<C, N>, R>
+where
+    F2: PrimeField,
+    C: FpConfig<N>,
+    H: DuplexHash<Fp<C, N>>,
+    R: RngCore + CryptoRng,
+{
+    fn add_non_native_scalars(&mut self, input: &[F2]) -> ProofResult<()> {
+        for i in input {
+            self.public_units(&to_non_native_limbs::<F2, Fp<C, N>>(i))?;
+            i.serialize_compressed(&mut self.transcript)?;
+        }
+        Ok(())
+    }
+}
+
+/// Points are written with the [`PointEncoding`][`crate::PointEncoding`] of the IO Pattern.
 impl<G, H, R> GroupWriter<G> for Merlin<H, u8, R>
 where
-    G: CurveGroup,
+    G: EncodableCurve,
     H: DuplexHash,
     R: RngCore + CryptoRng,
     Merlin<H, u8, R>: GroupPublic<G, Repr = Vec<u8>>,
@@ -58,3 +99,29 @@ where
         Ok(())
     }
 }
+
+impl<P, H, R> PairingWriter<P> for Merlin<H, u8, R>
+where
+    P: Pairing,
+    P::G1: EncodableCurve,
+    P::G2: EncodableCurve,
+    H: DuplexHash,
+    R: RngCore + CryptoRng,
+{
+    fn add_g1_points(&mut self, input: &[P::G1]) -> ProofResult<()> {
+        GroupWriter::<P::G1>::add_points(self, input)
+    }
+
+    fn add_g2_points(&mut self, input: &[P::G2]) -> ProofResult<()> {
+        GroupWriter::<P::G2>::add_points(self, input)
+    }
+
+    fn add_gt_elements(&mut self, input: &[PairingOutput<P>]) -> ProofResult<()> {
+        let mut buf = Vec::new();
+        for i in input {
+            i.serialize_compressed(&mut buf)?;
+        }
+        self.add_bytes(&buf)?;
+        Ok(())
+    }
+}
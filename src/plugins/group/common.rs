@@ -1,25 +1,68 @@
-use crate::{ByteChallenges, BytePublic, ProofResult};
+use std::io;
+
 use group::ff::PrimeField;
+use group::{Group, GroupEncoding};
+use rand::{CryptoRng, RngCore};
+
+use super::{FieldChallenges, FieldPublic, FieldUnit, GroupPublic};
+use crate::plugins::{
+    bytes_uniform_modp, challenge_short_integers, random_bytes_in_random_modp,
+    uniform_bytes_from_units, units_for_challenge_bytes,
+};
+use crate::{
+    Arthur, ByteChallenges, BytePublic, DuplexHash, IOPatternError, Merlin, ProofResult, Unit,
+    UnitTranscript,
+};
+
+// Implementation of basic traits for bridging zkcrypto and nimue
 
-use super::{FieldChallenges, FieldPublic};
-use crate::plugins::bytes_uniform_modp;
+impl<F: PrimeField> zeroize::Zeroize for FieldUnit<F> {
+    fn zeroize(&mut self) {
+        self.0 = F::ZERO;
+    }
+}
+
+impl<F: PrimeField> Unit for FieldUnit<F> {
+    fn write(bunch: &[Self], w: &mut impl io::Write) -> Result<(), io::Error> {
+        for b in bunch {
+            w.write_all(b.0.to_repr().as_ref())?;
+        }
+        Ok(())
+    }
+
+    fn read(r: &mut impl io::Read, bunch: &mut [Self]) -> Result<(), io::Error> {
+        let mut repr = F::Repr::default();
+        for b in bunch.iter_mut() {
+            r.read_exact(repr.as_mut())?;
+            b.0 = Option::from(F::from_repr(repr)).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Unable to deserialize into Field.",
+                )
+            })?;
+        }
+        Ok(())
+    }
+}
 
 /// Convert a byte array to a field element.
 ///
 /// This function should be equivalent to arkworks' `PrimeField::from_be_bytes_mod_order`.
 /// XXX. A better way to do this?
 /// Took less time to implement this than to figure out group's API..
-fn from_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
+pub(super) fn from_bytes_mod_order<F: PrimeField>(bytes: &[u8]) -> F {
     let basis = F::from(256);
     bytes
         .iter()
         .fold(F::ZERO, |acc, &b| acc * basis + F::from(b as u64))
 }
 
+// Bytes <-> Field elements interactions:
+
 impl<F, T> FieldChallenges<F> for T
 where
     F: PrimeField,
-    T: ByteChallenges,
+    T: UnitTranscript<u8>,
 {
     fn fill_challenge_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
         let mut buf = vec![0; bytes_uniform_modp(F::NUM_BITS)];
@@ -31,12 +74,69 @@ where
 
         Ok(())
     }
+
+    fn fill_challenge_short_scalars(&mut self, output: &mut [F], bits: usize) -> ProofResult<()> {
+        fill_short_scalars(self, output, bits)
+    }
+}
+
+/// Squeeze scalars of `bits` bits from bytes.
+fn fill_short_scalars<F: PrimeField>(
+    transcript: &mut impl ByteChallenges,
+    output: &mut [F],
+    bits: usize,
+) -> ProofResult<()> {
+    let integers = challenge_short_integers(transcript, output.len(), bits, F::NUM_BITS as usize)?;
+    for (o, integer) in output.iter_mut().zip(integers) {
+        *o = from_bytes_mod_order(&integer);
+    }
+    Ok(())
+}
+
+/// Squeeze one scalar per short scalar, and keep its `bits` least significant bits.
+///
+/// The encoding of [`PrimeField::to_repr`] is implementation-specific,
+/// so bits are extracted with [`PrimeField::is_odd`].
+/// The result is $2^{\text{bits}} / p$-close to uniform.
+pub(super) fn fill_truncated_short_scalars<F, T>(
+    transcript: &mut T,
+    output: &mut [F],
+    bits: usize,
+) -> ProofResult<()>
+where
+    F: PrimeField,
+    T: FieldChallenges<F> + ?Sized,
+{
+    if bits == 0 || bits >= F::NUM_BITS as usize {
+        return Err(IOPatternError::from(format!(
+            "Short scalars must have between 1 and {} bits, got {}",
+            F::NUM_BITS - 1,
+            bits
+        ))
+        .into());
+    }
+    transcript.fill_challenge_scalars(output)?;
+    for o in output.iter_mut() {
+        let (mut x, mut short, mut power) = (*o, F::ZERO, F::ONE);
+        for _ in 0..bits {
+            let bit = if bool::from(x.is_odd()) {
+                F::ONE
+            } else {
+                F::ZERO
+            };
+            short += power * bit;
+            x = (x - bit) * F::TWO_INV;
+            power = power.double();
+        }
+        *o = short;
+    }
+    Ok(())
 }
 
 impl<F, T> FieldPublic<F> for T
 where
     F: PrimeField,
-    T: BytePublic,
+    T: UnitTranscript<u8>,
 {
     type Repr = Vec<u8>;
 
@@ -47,3 +147,214 @@ where
         Ok(buf)
     }
 }
+
+// Field <-> Field interactions:
+
+/// Pack the encoding of group elements into field elements.
+///
+/// [`group`] does not expose the coordinates of points, so points are absorbed as their
+/// [`GroupEncoding::to_bytes`], split into big-endian chunks small enough to fit in `F`.
+pub(super) fn points_to_units<G, F>(input: &[G]) -> Vec<FieldUnit<F>>
+where
+    G: GroupEncoding,
+    F: PrimeField,
+{
+    let chunk_len = random_bytes_in_random_modp(F::NUM_BITS);
+    input
+        .iter()
+        .flat_map(|point| {
+            let bytes = point.to_bytes();
+            bytes
+                .as_ref()
+                .chunks(chunk_len)
+                .map(|chunk| FieldUnit(from_bytes_mod_order(chunk)))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// The number of field elements absorbed per point by [`points_to_units`].
+pub(super) fn units_per_point<G: GroupEncoding, F: PrimeField>() -> usize {
+    let chunk_len = random_bytes_in_random_modp(F::NUM_BITS);
+    G::Repr::default().as_ref().len().div_ceil(chunk_len)
+}
+
+/// The little-endian bytes of the canonical representative of `x`.
+///
+/// [`PrimeField::to_repr`] may be either little- or big-endian; the endianness is detected from the encoding of one.
+fn to_le_bytes<F: PrimeField>(x: &F) -> Vec<u8> {
+    let mut bytes = x.to_repr().as_ref().to_vec();
+    if F::ONE.to_repr().as_ref()[0] != 1 {
+        bytes.reverse();
+    }
+    bytes
+}
+
+/// Fill `output` with bytes close to uniform, cf. [`uniform_bytes_from_units`].
+fn fill_challenge_bytes_native<F: PrimeField>(
+    transcript: &mut impl UnitTranscript<FieldUnit<F>>,
+    output: &mut [u8],
+    security_level: usize,
+) -> Result<(), IOPatternError> {
+    let k = units_for_challenge_bytes(output.len(), F::NUM_BITS, security_level);
+    let mut units = vec![FieldUnit(F::ZERO); k];
+    transcript.fill_challenge_units(&mut units)?;
+    let units = units
+        .iter()
+        .map(|unit| to_le_bytes(&unit.0))
+        .collect::<Vec<_>>();
+    // The modulus is the successor of the canonical representative of -1.
+    let mut modulus = to_le_bytes(&-F::ONE);
+    for byte in modulus.iter_mut() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+    uniform_bytes_from_units(&modulus, &units, output);
+    Ok(())
+}
+
+impl<H, R, F> FieldPublic<F> for Merlin<H, FieldUnit<F>, R>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+    R: RngCore + CryptoRng,
+{
+    type Repr = ();
+
+    fn public_scalars(&mut self, input: &[F]) -> ProofResult<Self::Repr> {
+        let units = input.iter().copied().map(FieldUnit).collect::<Vec<_>>();
+        self.public_units(&units)?;
+        Ok(())
+    }
+}
+
+impl<H, F> FieldPublic<F> for Arthur<'_, H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    type Repr = ();
+
+    fn public_scalars(&mut self, input: &[F]) -> ProofResult<Self::Repr> {
+        let units = input.iter().copied().map(FieldUnit).collect::<Vec<_>>();
+        self.public_units(&units)?;
+        Ok(())
+    }
+}
+
+impl<H, R, F> FieldChallenges<F> for Merlin<H, FieldUnit<F>, R>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+    R: RngCore + CryptoRng,
+{
+    fn fill_challenge_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        let mut units = vec![FieldUnit(F::ZERO); output.len()];
+        self.fill_challenge_units(&mut units)?;
+        output.iter_mut().zip(units).for_each(|(o, u)| *o = u.0);
+        Ok(())
+    }
+
+    fn fill_challenge_short_scalars(&mut self, output: &mut [F], bits: usize) -> ProofResult<()> {
+        fill_short_scalars(self, output, bits)
+    }
+}
+
+impl<H, F> FieldChallenges<F> for Arthur<'_, H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn fill_challenge_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        let mut units = vec![FieldUnit(F::ZERO); output.len()];
+        self.fill_challenge_units(&mut units)?;
+        output.iter_mut().zip(units).for_each(|(o, u)| *o = u.0);
+        Ok(())
+    }
+
+    fn fill_challenge_short_scalars(&mut self, output: &mut [F], bits: usize) -> ProofResult<()> {
+        fill_short_scalars(self, output, bits)
+    }
+}
+
+impl<H, R, F, G> GroupPublic<G> for Merlin<H, FieldUnit<F>, R>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+    R: RngCore + CryptoRng,
+    G: Group + GroupEncoding,
+{
+    type Repr = ();
+
+    fn public_points(&mut self, input: &[G]) -> ProofResult<Self::Repr> {
+        self.public_units(&points_to_units(input))?;
+        Ok(())
+    }
+}
+
+impl<H, F, G> GroupPublic<G> for Arthur<'_, H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+    G: Group + GroupEncoding,
+{
+    type Repr = ();
+
+    fn public_points(&mut self, input: &[G]) -> ProofResult<Self::Repr> {
+        self.public_units(&points_to_units(input))?;
+        Ok(())
+    }
+}
+
+// Field  <-> Bytes interactions:
+
+impl<H, R, F> BytePublic for Merlin<H, FieldUnit<F>, R>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+    R: RngCore + CryptoRng,
+{
+    fn public_bytes(&mut self, input: &[u8]) -> Result<(), IOPatternError> {
+        for &byte in input {
+            self.public_units(&[FieldUnit(F::from(byte as u64))])?;
+        }
+        Ok(())
+    }
+}
+
+impl<H, F> BytePublic for Arthur<'_, H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn public_bytes(&mut self, input: &[u8]) -> Result<(), IOPatternError> {
+        for &byte in input {
+            self.public_units(&[FieldUnit(F::from(byte as u64))])?;
+        }
+        Ok(())
+    }
+}
+
+impl<H, R, F> ByteChallenges for Merlin<H, FieldUnit<F>, R>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+    R: RngCore + CryptoRng,
+{
+    fn fill_challenge_bytes(&mut self, output: &mut [u8]) -> Result<(), IOPatternError> {
+        fill_challenge_bytes_native(self, output, H::SECURITY_LEVEL)
+    }
+}
+
+impl<H, F> ByteChallenges for Arthur<'_, H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn fill_challenge_bytes(&mut self, output: &mut [u8]) -> Result<(), IOPatternError> {
+        fill_challenge_bytes_native(self, output, H::SECURITY_LEVEL)
+    }
+}
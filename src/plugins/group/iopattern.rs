@@ -1,11 +1,12 @@
 use group::{ff::PrimeField, Group, GroupEncoding};
 
 use crate::{
-    plugins::{bytes_modp, bytes_uniform_modp},
+    plugins::{bytes_modp, bytes_short_scalar, bytes_uniform_modp, units_for_challenge_bytes},
     ByteIOPattern, DuplexHash, IOPattern,
 };
 
-use super::{FieldIOPattern, GroupIOPattern};
+use super::common::units_per_point;
+use super::{FieldIOPattern, FieldUnit, GroupIOPattern};
 
 impl<F, H> FieldIOPattern<F> for IOPattern<H>
 where
@@ -19,6 +20,10 @@ where
     fn challenge_scalars(self, count: usize, label: &str) -> Self {
         self.challenge_bytes(count * bytes_uniform_modp(F::NUM_BITS), label)
     }
+
+    fn challenge_short_scalars(self, count: usize, bits: usize, label: &str) -> Self {
+        self.challenge_bytes(count * bytes_short_scalar(bits), label)
+    }
 }
 
 impl<G, H> GroupIOPattern<G> for IOPattern<H>
@@ -27,8 +32,61 @@ where
     G::Repr: AsRef<[u8]>,
     H: DuplexHash,
 {
+    /// Points are sent with their [`GroupEncoding`]: other [`PointEncoding`][`crate::PointEncoding`]s are not supported.
     fn add_points(self, count: usize, label: &str) -> Self {
+        assert_eq!(
+            self.point_encoding(),
+            crate::PointEncoding::Compressed,
+            "Groups only support their canonical encoding."
+        );
         let n = G::Repr::default().as_ref().len();
         self.add_bytes(count * n, label)
     }
 }
+
+impl<F, H> FieldIOPattern<F> for IOPattern<H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn add_scalars(self, count: usize, label: &str) -> Self {
+        self.absorb(count, label)
+    }
+
+    fn challenge_scalars(self, count: usize, label: &str) -> Self {
+        self.squeeze(count, label)
+    }
+
+    /// Short scalars are squeezed as bytes, cf. [`ByteIOPattern::challenge_bytes`].
+    fn challenge_short_scalars(self, count: usize, bits: usize, label: &str) -> Self {
+        self.challenge_bytes(count * bytes_short_scalar(bits), label)
+    }
+}
+
+impl<F, H> ByteIOPattern for IOPattern<H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn add_bytes(self, count: usize, label: &str) -> Self {
+        self.absorb(count, label)
+    }
+
+    fn challenge_bytes(self, count: usize, label: &str) -> Self {
+        self.squeeze(
+            units_for_challenge_bytes(count, F::NUM_BITS, H::SECURITY_LEVEL),
+            label,
+        )
+    }
+}
+
+impl<F, G, H> GroupIOPattern<G> for IOPattern<H, FieldUnit<F>>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn add_points(self, count: usize, label: &str) -> Self {
+        self.absorb(count * units_per_point::<G, F>(), label)
+    }
+}
@@ -1,8 +1,39 @@
 //! This adds support also for [curve25519-dalek](https://github.com/dalek-cryptography/curve25519-dalek) with feature flag `group`.
+//!
+//! Transcripts can be built over bytes, or natively over a prime field `F` with a sponge
+//! operating on [`FieldUnit<F>`][`group::FieldUnit`], e.g. an algebraic hash over the base field of [`pasta_curves`](https://github.com/zcash/pasta_curves).
+//! Over [`FieldUnit<F>`][`group::FieldUnit`], scalars of `F` are absorbed and squeezed natively rather than as bytes.
+//!
+//! **Compatibility.** For this reason, the blanket implementations of [`FieldPublic`][`group::FieldPublic`] and [`FieldChallenges`][`group::FieldChallenges`]
+//! are bounded by [`UnitTranscript<u8>`][`crate::UnitTranscript`], where they used to be bounded by
+//! [`BytePublic`][`crate::BytePublic`] and [`ByteChallenges`][`crate::ByteChallenges`].
+//! Custom transcripts that only implement the byte traits over other units must now implement
+//! [`FieldPublic`][`group::FieldPublic`] and [`FieldChallenges`][`group::FieldChallenges`] themselves, e.g. by forwarding to their byte methods.
+//! The default short scalars of [`FieldChallenges`][`group::FieldChallenges`] are only available over
+//! [`PrimeField`][`::group::ff::PrimeField`]s.
 mod common;
 mod iopattern;
+/// The Poseidon hash function over [`FieldUnit`]s.
+pub mod poseidon;
 mod reader;
 mod writer;
 
-super::traits::field_traits!(group::ff::Field);
+super::traits::field_traits!(
+    group::ff::Field,
+    short_scalars: common::fill_truncated_short_scalars,
+    where F: group::ff::PrimeField
+);
 super::traits::group_traits!(group::Group, Scalar: group::ff::Field);
+
+/// An element of the prime field `F`, used as the native [`Unit`][`crate::Unit`] of algebraic sponges.
+///
+/// The wrapper is needed because [`Unit`][`crate::Unit`] cannot be implemented for all [`group::ff::PrimeField`]s at once.
+/// Units are encoded on the wire as [`group::ff::PrimeField::to_repr`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FieldUnit<F: group::ff::PrimeField>(pub F);
+
+impl<F: group::ff::PrimeField> From<F> for FieldUnit<F> {
+    fn from(value: F) -> Self {
+        Self(value)
+    }
+}
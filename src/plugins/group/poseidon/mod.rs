@@ -0,0 +1,125 @@
+//! The Poseidon permutation over [`FieldUnit`]s, as [`PoseidonSponge`][`crate::plugins::ark::poseidon::PoseidonSponge`] is over arkworks fields.
+use group::ff::PrimeField;
+
+use super::FieldUnit;
+use crate::hash::sponge::{algebraic_security_level, Sponge};
+
+#[derive(Clone)]
+pub struct PoseidonSponge<F: PrimeField, const R: usize, const N: usize> {
+    /// Number of rounds in a full-round operation.
+    pub full_rounds: usize,
+    /// Number of rounds in a partial-round operation.
+    pub partial_rounds: usize,
+    /// Exponent used in S-boxes.
+    pub alpha: u64,
+    /// Additive Round keys, indexed by `ark[round_num][state_element_index]`.
+    pub ark: &'static [[F; N]],
+    /// Maximally Distance Separating (MDS) Matrix.
+    pub mds: &'static [[F; N]],
+
+    /// Sponge state
+    pub state: [FieldUnit<F>; N],
+}
+
+pub type PoseidonHash<F, const R: usize, const N: usize> =
+    crate::hash::sponge::DuplexSponge<PoseidonSponge<F, R, N>>;
+
+impl<F: PrimeField, const R: usize, const N: usize> AsRef<[FieldUnit<F>]>
+    for PoseidonSponge<F, R, N>
+{
+    fn as_ref(&self) -> &[FieldUnit<F>] {
+        &self.state
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> AsMut<[FieldUnit<F>]>
+    for PoseidonSponge<F, R, N>
+{
+    fn as_mut(&mut self) -> &mut [FieldUnit<F>] {
+        &mut self.state
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> zeroize::Zeroize for PoseidonSponge<F, R, N> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl<F: PrimeField, const R: usize, const N: usize> PoseidonSponge<F, R, N> {
+    fn round(&self, state: &mut [F; N], round: usize, is_full_round: bool) {
+        for (x, c) in state.iter_mut().zip(&self.ark[round]) {
+            *x += c;
+        }
+        // Full rounds apply the S-box to every element of the state, partial rounds only to the first one.
+        let sboxes = if is_full_round { N } else { 1 };
+        for x in &mut state[..sboxes] {
+            *x = x.pow_vartime([self.alpha]);
+        }
+        let previous = *state;
+        for (x, row) in state.iter_mut().zip(self.mds) {
+            *x = row
+                .iter()
+                .zip(&previous)
+                .fold(F::ZERO, |acc, (m, y)| acc + *m * y);
+        }
+    }
+}
+
+impl<F, const R: usize, const N: usize> Sponge for PoseidonSponge<F, R, N>
+where
+    PoseidonSponge<F, R, N>: Default,
+    F: PrimeField,
+{
+    type U = FieldUnit<F>;
+    const N: usize = N;
+    const R: usize = R;
+    const SECURITY_LEVEL: usize = algebraic_security_level(N - R, F::NUM_BITS);
+
+    /// The IV is written in the capacity as an integer modulo $p$, as for arkworks' [`PoseidonSponge`][`crate::plugins::ark::poseidon::PoseidonSponge`].
+    fn new(iv: [u8; 32]) -> Self {
+        assert!(N >= 1);
+        let mut sponge = Self::default();
+        sponge.state[R] = FieldUnit(super::common::from_bytes_mod_order(&iv));
+        sponge
+    }
+
+    fn permute(&mut self) {
+        let full_rounds_over_2 = self.full_rounds / 2;
+        let mut state = self.state.map(|x| x.0);
+        for round in 0..self.full_rounds + self.partial_rounds {
+            let is_full_round =
+                round < full_rounds_over_2 || round >= full_rounds_over_2 + self.partial_rounds;
+            self.round(&mut state, round, is_full_round);
+        }
+        self.state = state.map(FieldUnit);
+    }
+}
+
+/// Initialization of constants.
+#[allow(unused)]
+macro_rules! poseidon_sponge {
+    ($(#[$attr: meta])* $name: ident, $path: tt) => {
+        $(#[$attr])*
+        pub type $name = crate::hash::sponge::DuplexSponge<
+            poseidon::PoseidonSponge<$path::Field, { $path::R }, { $path::N }>,
+        >;
+
+        impl Default for poseidon::PoseidonSponge<$path::Field, { $path::R }, { $path::N }> {
+            fn default() -> Self {
+                Self {
+                    full_rounds: $path::FULL_ROUNDS,
+                    partial_rounds: $path::TOTAL_ROUNDS - $path::FULL_ROUNDS,
+                    alpha: $path::ALPHA,
+                    ark: $path::ARK,
+                    mds: $path::MDS,
+                    state: [super::super::FieldUnit(group::ff::Field::ZERO); $path::N],
+                }
+            }
+        }
+    };
+}
+
+/// The Poseidon parameters of [Orchard](https://zips.z.cash/protocol/protocol.pdf#poseidonhash) (`P128Pow5T3`) over the Pasta fields.
+#[cfg(feature = "pasta_curves")]
+pub mod pasta;
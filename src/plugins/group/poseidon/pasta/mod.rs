@@ -0,0 +1,17 @@
+//! Width 3, rate 2, $\alpha = 5$, with 8 full rounds and 56 partial rounds,
+//! generated with the Grain LFSR of the [reference implementation](https://extgit.iaik.tugraz.at/krypto/hadeshash).
+use crate::plugins::group::poseidon;
+
+mod fp_3_2;
+mod fq_3_2;
+
+poseidon_sponge!(
+    /// Poseidon over the base field of Pallas.
+    PoseidonPallas,
+    fp_3_2
+);
+poseidon_sponge!(
+    /// Poseidon over the base field of Vesta.
+    PoseidonVesta,
+    fq_3_2
+);
@@ -1,6 +1,8 @@
-use super::FieldReader;
-use crate::{Arthur, ByteReader, DuplexHash, ProofError};
+use super::common::points_to_units;
+use super::{FieldReader, FieldUnit, GroupReader};
+use crate::{Arthur, ByteReader, DuplexHash, ProofError, ProofResult, UnitTranscript};
 use group::ff::PrimeField;
+use group::prime::PrimeGroup;
 
 impl<'a, F, H, const N: usize> FieldReader<F> for Arthur<'a, H>
 where
@@ -16,3 +18,65 @@ where
         Ok(())
     }
 }
+
+/// Points are rejected if their encoding is invalid. The identity is accepted, as in the arkworks plugin.
+///
+/// Only prime-order groups can be read: the encodings of a [`PrimeGroup`] only represent elements of the group,
+/// so that no subgroup check is needed.
+/// Points of curves with a cofactor, such as the Edwards form of Curve25519, should be sent
+/// as elements of a prime-order group, such as Ristretto.
+impl<'a, G, H> GroupReader<G> for Arthur<'a, H>
+where
+    G: PrimeGroup + Default,
+    H: DuplexHash,
+{
+    fn fill_next_points(&mut self, output: &mut [G]) -> crate::ProofResult<()> {
+        let mut buf = G::Repr::default();
+        for o in output.iter_mut() {
+            self.fill_next_bytes(buf.as_mut())?;
+            *o = decode_point(&buf)?;
+        }
+        Ok(())
+    }
+}
+
+fn decode_point<G: PrimeGroup>(repr: &G::Repr) -> ProofResult<G> {
+    Option::<G>::from(G::from_bytes(repr)).ok_or(ProofError::SerializationError)
+}
+
+impl<'a, F, H> FieldReader<F> for Arthur<'a, H, FieldUnit<F>>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn fill_next_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        let mut units = vec![FieldUnit(F::ZERO); output.len()];
+        self.fill_next_units(&mut units)?;
+        output.iter_mut().zip(units).for_each(|(o, u)| *o = u.0);
+        Ok(())
+    }
+}
+
+/// Points are validated as in the byte-oriented reader, and then absorbed as field elements.
+impl<'a, F, G, H> GroupReader<G> for Arthur<'a, H, FieldUnit<F>>
+where
+    F: PrimeField,
+    G: PrimeGroup + Default,
+    H: DuplexHash<FieldUnit<F>>,
+{
+    fn fill_next_points(&mut self, output: &mut [G]) -> ProofResult<()> {
+        let mut buf = G::Repr::default();
+        for o in output.iter_mut() {
+            let len = buf.as_ref().len();
+            if self.transcript.len() < len {
+                return Err(ProofError::SerializationError);
+            }
+            let (repr, rest) = self.transcript.split_at(len);
+            buf.as_mut().copy_from_slice(repr);
+            self.transcript = rest;
+            *o = decode_point(&buf)?;
+            self.public_units(&points_to_units(core::slice::from_ref(o)))?;
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
 use group::{ff::PrimeField, Group, GroupEncoding};
 use rand::{CryptoRng, RngCore};
 
-use super::{FieldPublic, FieldWriter, GroupPublic, GroupWriter};
+use super::{FieldPublic, FieldUnit, FieldWriter, GroupPublic, GroupWriter};
 use crate::{ByteWriter, DuplexHash, Merlin, ProofResult};
 
 impl<F, H, R> FieldWriter<F> for Merlin<H, u8, R>
@@ -48,3 +48,33 @@ where
         Ok(())
     }
 }
+
+impl<F, H, R> FieldWriter<F> for Merlin<H, FieldUnit<F>, R>
+where
+    F: PrimeField,
+    H: DuplexHash<FieldUnit<F>>,
+    R: RngCore + CryptoRng,
+{
+    fn add_scalars(&mut self, input: &[F]) -> ProofResult<()> {
+        let units = input.iter().copied().map(FieldUnit).collect::<Vec<_>>();
+        self.add_units(&units)?;
+        Ok(())
+    }
+}
+
+/// Points are absorbed as field elements, cf. [`GroupPublic`], and written in the transcript with their [`GroupEncoding`].
+impl<F, G, H, R> GroupWriter<G> for Merlin<H, FieldUnit<F>, R>
+where
+    F: PrimeField,
+    G: Group + GroupEncoding,
+    H: DuplexHash<FieldUnit<F>>,
+    R: RngCore + CryptoRng,
+{
+    fn add_points(&mut self, input: &[G]) -> ProofResult<()> {
+        self.public_points(input)?;
+        for point in input {
+            self.transcript.extend_from_slice(point.to_bytes().as_ref());
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,6 @@
 //!  Bindings for some popular libearies using zero-knowledge.
 
-/// Extension traits macros, for both arkworks and group.
-#[cfg(any(feature = "ark", feature = "group"))]
+/// Extension traits macros, for arkworks, group, and small fields.
 mod traits;
 
 #[cfg(feature = "ark")]
@@ -13,6 +12,9 @@ pub mod ark;
 /// This plugin is experimental and has not yet been thoroughly tested.
 pub mod group;
 
+/// Native support for small prime fields (Goldilocks, BabyBear, Mersenne31) and the hashes defined over them.
+pub mod small_fields;
+
 /// Proof of work (PoW) challenges.
 pub mod pow;
 
@@ -22,6 +24,105 @@ pub(super) const fn bytes_uniform_modp(modulus_bits: u32) -> usize {
     (modulus_bits as usize + 128) / 8
 }
 
+/// Bytes that can be extracted from a (pseudo-random) uniform element of F,
+/// discarding the most significant bits as [`ark_crypto_primitives`](https://github.com/arkworks-rs/crypto-primitives) does.
+#[allow(unused)]
+pub(super) const fn random_bytes_in_random_modp(modulus_bits: u32) -> usize {
+    (modulus_bits as usize - 1) / 8
+}
+
+/// Units an algebraic transcript squeezes in a single call to
+/// [`ByteChallenges::fill_challenge_bytes`][`crate::ByteChallenges::fill_challenge_bytes`] for `count` bytes:
+/// the least `k` such that $p^k \geq 2^{8 \cdot \mathtt{count} + \lambda}$, for any modulus $p$ of `modulus_bits` bits.
+///
+/// The statistical security parameter $\lambda$ is the largest of the default [`STATISTICAL_SECURITY`][`crate::STATISTICAL_SECURITY`]
+/// and the `security_level` of the sponge, so that the bytes are never further from uniform than the sponge is secure.
+#[allow(unused)]
+pub(super) const fn units_for_challenge_bytes(
+    count: usize,
+    modulus_bits: u32,
+    security_level: usize,
+) -> usize {
+    if count == 0 {
+        return 0;
+    }
+    let lambda = if security_level > crate::STATISTICAL_SECURITY {
+        security_level
+    } else {
+        crate::STATISTICAL_SECURITY
+    };
+    (8 * count + lambda).div_ceil(modulus_bits as usize - 1)
+}
+
+/// Fill `output` with the least significant bytes (in little-endian order) of $\sum_i x_i p^i$,
+/// where `units` are the little-endian encodings of the $x_i$ and `modulus` the one of $p$.
+///
+/// If the $x_i$ are uniform in $[0, p)$, the sum is uniform in $[0, p^k)$,
+/// and its residue modulo $2^{8 \cdot \mathtt{output.len()}}$ is $2^{-\lambda}$-close to uniform
+/// when $k$ is [`units_for_challenge_bytes`]`(output.len(), ..)`.
+#[allow(unused)]
+pub(super) fn uniform_bytes_from_units(modulus: &[u8], units: &[Vec<u8>], output: &mut [u8]) {
+    let len = output.len();
+    // Horner's rule modulo 256^len, with one byte per (unreduced) limb.
+    let mut acc = vec![0u64; len];
+    for unit in units.iter().rev() {
+        let mut next = vec![0u64; len];
+        for (i, &a) in acc.iter().enumerate().filter(|(_, &a)| a != 0) {
+            for (n, &b) in next[i..].iter_mut().zip(modulus) {
+                *n += a * b as u64;
+            }
+        }
+        for (n, &b) in next.iter_mut().zip(unit) {
+            *n += b as u64;
+        }
+        let mut carry = 0;
+        for n in next.iter_mut() {
+            carry += *n;
+            *n = carry & 0xff;
+            carry >>= 8;
+        }
+        acc = next;
+    }
+    for (o, a) in output.iter_mut().zip(acc) {
+        *o = a as u8;
+    }
+}
+
+/// Bytes squeezed for a short scalar of `bits` bits.
+#[allow(unused)]
+pub(super) const fn bytes_short_scalar(bits: usize) -> usize {
+    bits.div_ceil(8)
+}
+
+/// Squeeze `count` big-endian integers of `bits` bits each, in a single call to [`ByteChallenges::fill_challenge_bytes`][`crate::ByteChallenges::fill_challenge_bytes`].
+#[allow(unused)]
+pub(super) fn challenge_short_integers(
+    transcript: &mut impl crate::ByteChallenges,
+    count: usize,
+    bits: usize,
+    modulus_bits: usize,
+) -> crate::ProofResult<Vec<Vec<u8>>> {
+    if bits == 0 || bits >= modulus_bits {
+        return Err(crate::IOPatternError::from(format!(
+            "Short scalars must have between 1 and {} bits, got {}",
+            modulus_bits - 1,
+            bits
+        ))
+        .into());
+    }
+    let len = bytes_short_scalar(bits);
+    let mut bytes = vec![0u8; count * len];
+    transcript.fill_challenge_bytes(&mut bytes)?;
+    Ok(bytes
+        .chunks(len)
+        .map(|chunk| {
+            let mut chunk = chunk.to_vec();
+            chunk[0] &= 0xff >> (len * 8 - bits);
+            chunk
+        })
+        .collect())
+}
+
 /// Bits needed in order to encode an element of F.
 #[allow(unused)]
 pub(super) const fn bytes_modp(modulus_bits: u32) -> usize {
@@ -1,6 +1,14 @@
+use core::marker::PhantomData;
+use core::ops::Range;
+use std::time::{Duration, Instant};
+
+use rand::{CryptoRng, RngCore};
+
+use crate::hash::keccak::{f1600_batch, AlignedKeccakState, BATCH_SIZE};
+use crate::hash::sponge::Sponge;
 use crate::{
-    hash::Keccak, Arthur, ByteChallenges, ByteIOPattern, ByteReader, ByteWriter, DuplexHash,
-    IOPattern, Merlin, ProofError, ProofResult,
+    hash::Keccak, Arthur, ByteChallenges, ByteIOPattern, BytePublic, ByteReader, ByteWriter,
+    DuplexHash, IOPattern, Merlin, ProofError, ProofResult, Unit,
 };
 
 /// Wrapper type for a challenge generated via a proof-of-work.
@@ -9,77 +17,377 @@ pub struct PoWChal(pub u128);
 
 /// [`IOPattern`] for proof-of-work challenges.
 pub trait PoWIOPattern {
-    /// Adds a [`PoWChal`] to the [`IOPattern`].
+    /// Adds a [`PoWChal`] of difficulty `bits` to the [`IOPattern`].
     ///
     /// In order to squeeze a proof-of-work challenge, we extract a 32-byte challenge using
     /// the byte interface, and then we find a 16-byte nonce that satisfies the proof-of-work.
     /// The nonce a 64-bit integer encoded as an unsigned integer and written in big-endian and added
     /// to the protocol transcript as the nonce for the proof-of-work.
     ///
-    /// The number of bits used for the proof of work are **not** encoded within the [`IOPattern`].
-    /// It is up to the implementor to change the domain separator or the label in order to reflect changes in the proof
-    /// in order to preserve simulation extractability.
-    fn challenge_pow(self, label: &str) -> Self;
+    /// The difficulty is bound to the challenge: before squeezing it,
+    /// one byte holding `bits` is absorbed as public input.
+    /// Hence prover and verifier using a different number of bits derive different challenges.
+    /// The bytes absorbed and written are labelled `"{label}-bits"` and `"{label}-nonce"`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is not in `1..=128`, or exceeds the [`DuplexHash::SECURITY_LEVEL`] of the transcript.
+    fn challenge_pow(self, bits: usize, label: &str) -> Self;
 }
 
-impl PoWIOPattern for IOPattern {
-    fn challenge_pow(self, label: &str) -> Self {
-        // 16 bytes challenge and 16 bytes nonce (that will be written)
-        self.challenge_bytes(32, label).add_bytes(8, "pow-nonce")
+impl<H, U> PoWIOPattern for IOPattern<H, U>
+where
+    U: Unit,
+    H: DuplexHash<U>,
+    IOPattern<H, U>: ByteIOPattern,
+{
+    fn challenge_pow(self, bits: usize, label: &str) -> Self {
+        let max_bits = max_pow_bits(MAX_POW_BITS, H::SECURITY_LEVEL);
+        assert!(
+            (1..=max_bits).contains(&bits),
+            "Proof-of-work difficulty must be in 1..={max_bits}"
+        );
+        // the difficulty (one byte), 32 bytes challenge and 8 bytes nonce (that will be written)
+        self.add_bytes(1, &format!("{label}-bits"))
+            .challenge_bytes(32, label)
+            .add_bytes(8, &format!("{label}-nonce"))
     }
 }
 
-pub trait PoWChallenge {
-    /// Extension trait for generating a proof-of-work challenge.
-    fn challenge_pow(&mut self, bits: usize) -> ProofResult<PoWChal>;
+/// The maximum difficulty of a proof of work, i.e. the size of [`PoWChal`].
+const MAX_POW_BITS: usize = 128;
+
+/// The maximum difficulty of a proof of work of at most `bits` bits, using a hash of the given security level:
+/// finding a nonce should never be harder than attacking the hash itself.
+const fn max_pow_bits(bits: usize, security_level: usize) -> usize {
+    if bits < security_level {
+        bits
+    } else {
+        security_level
+    }
 }
 
-impl PoWChallenge for Merlin
+/// Returns true if the `bits` most significant bits of `chal` are zero.
+#[inline]
+fn is_valid_pow(chal: u128, bits: usize) -> bool {
+    chal.leading_zeros() as usize >= bits
+}
+
+/// Absorbs the difficulty and squeezes the 32-byte challenge seeding the grinding hash.
+fn pow_challenge<T, S>(transcript: &mut T, bits: usize) -> ProofResult<[u8; 32]>
 where
-    Merlin: ByteWriter,
+    T: BytePublic + ByteChallenges,
+    S: PoWSolver,
 {
-    fn challenge_pow(&mut self, bits: usize) -> ProofResult<PoWChal> {
-        // Seed a new hash with the 32-byte challenge.
-        let mut challenge = [0u8; 32];
-        self.fill_challenge_bytes(&mut challenge)?;
-        let hash = Keccak::new(challenge);
+    if bits == 0 || bits > S::MAX_BITS {
+        return Err(ProofError::InvalidIO(
+            format!(
+                "Proof-of-work difficulty must be in 1..={}, got {bits}",
+                S::MAX_BITS
+            )
+            .into(),
+        ));
+    }
+    // The difficulty fits in one byte, as S::MAX_BITS <= MAX_POW_BITS.
+    transcript.public_bytes(&[bits as u8])?;
+    Ok(transcript.challenge_bytes::<32>()?)
+}
+
+/// The hash function used to grind a proof of work.
+///
+/// It is independent from the hash function used for the transcript:
+/// the 32-byte challenge squeezed from the transcript seeds the grinding hash,
+/// which maps each 64-bit nonce to a 128-bit integer.
+/// A nonce is valid for `bits` bits of work if the `bits` most significant bits of its hash are zero.
+pub trait PoWSolver {
+    /// The maximum difficulty this hash can be used for.
+    const MAX_BITS: usize = MAX_POW_BITS;
 
-        // Output buffer for the hash
+    /// Hashes `nonce` under the seed `challenge`.
+    fn hash(challenge: [u8; 32], nonce: u64) -> u128;
+
+    /// Hashes each nonce under its seed, cf. [`PoWSolver::hash`].
+    fn hash_batch(inputs: &[([u8; 32], u64)]) -> Vec<u128> {
+        inputs
+            .iter()
+            .map(|&(challenge, nonce)| Self::hash(challenge, nonce))
+            .collect()
+    }
+
+    /// Returns the first nonce in `nonces` (and its hash) that satisfies a proof of work of `bits` bits.
+    fn solve_range(challenge: [u8; 32], bits: usize, nonces: Range<u64>) -> Option<(u64, u128)> {
+        nonces
+            .map(|nonce| (nonce, Self::hash(challenge, nonce)))
+            .find(|&(_, chal)| is_valid_pow(chal, bits))
+    }
+
+    /// Returns the first nonce (and its hash) that satisfies a proof of work of `bits` bits,
+    /// using the default [`Grinder`].
+    fn solve(challenge: [u8; 32], bits: usize) -> Option<(u64, u128)> {
+        Grinder::default().solve::<Self>(challenge, bits)
+    }
+}
+
+/// Keccak is the default grinding hash.
+///
+/// Absorbing the nonce and squeezing from a fresh Keccak sponge amounts to writing the nonce
+/// at the beginning of the state and permuting it, so that many states can be permuted at once
+/// with [`f1600_batch`]: by the prover when grinding, and by the verifier in [`verify_pow_batch`].
+impl PoWSolver for Keccak {
+    const MAX_BITS: usize = max_pow_bits(MAX_POW_BITS, Keccak::SECURITY_LEVEL);
+
+    fn hash(challenge: [u8; 32], nonce: u64) -> u128 {
         let mut chal_bytes = [0u8; 16];
+        Keccak::new(challenge)
+            .absorb_unchecked(&nonce.to_be_bytes())
+            .squeeze_unchecked(&mut chal_bytes);
+        u128::from_be_bytes(chal_bytes)
+    }
 
-        // Loop over a 64-bit integer to find a PoWChal sufficiently small.
-        for nonce in 0u64.. {
-            hash.clone()
-                .absorb_unchecked(&nonce.to_be_bytes())
-                .squeeze_unchecked(&mut chal_bytes);
-            let chal = u128::from_be_bytes(chal_bytes);
-            if (chal << bits) >> bits == chal {
-                self.add_bytes(&nonce.to_be_bytes())?;
-                return Ok(PoWChal(chal));
+    fn hash_batch(inputs: &[([u8; 32], u64)]) -> Vec<u128> {
+        let mut states = inputs
+            .iter()
+            .map(|&(challenge, nonce)| {
+                let mut state: AlignedKeccakState = Sponge::new(challenge);
+                state.as_mut()[..8].copy_from_slice(&nonce.to_be_bytes());
+                state
+            })
+            .collect::<Vec<_>>();
+        f1600_batch(&mut states);
+        states
+            .iter()
+            .map(|state| u128::from_be_bytes(state.as_ref()[..16].try_into().unwrap()))
+            .collect()
+    }
+
+    fn solve_range(challenge: [u8; 32], bits: usize, nonces: Range<u64>) -> Option<(u64, u128)> {
+        let state: AlignedKeccakState = Sponge::new(challenge);
+        let end = nonces.end;
+
+        // Loop over the range to find a PoWChal sufficiently small,
+        // trying BATCH_SIZE nonces at a time.
+        for first_nonce in nonces.step_by(BATCH_SIZE) {
+            let nonces: [u64; BATCH_SIZE] =
+                core::array::from_fn(|i| first_nonce.saturating_add(i as u64));
+            let mut states = nonces.map(|nonce| {
+                let mut state = state.clone();
+                state.as_mut()[..8].copy_from_slice(&nonce.to_be_bytes());
+                state
+            });
+            f1600_batch(&mut states);
+            for (&nonce, state) in nonces.iter().zip(&states) {
+                let chal = u128::from_be_bytes(state.as_ref()[..16].try_into().unwrap());
+                if nonce < end && is_valid_pow(chal, bits) {
+                    return Some((nonce, chal));
+                }
             }
         }
+        None
+    }
+}
 
-        // Congratulations, you wasted 2^64 Keccak calls. You're a winner.
-        Err(ProofError::InvalidProof)
+/// Units over which a [`DuplexHash`] can grind a proof of work.
+pub trait PoWUnit: Unit {
+    /// The number of (close to) uniform most significant bits produced by [`PoWUnit::grind`].
+    const MAX_BITS: usize;
+
+    /// Absorbs `nonce` into `hash` and squeezes a 128-bit integer,
+    /// whose most significant bits are (close to) uniformly distributed.
+    fn grind<H: DuplexHash<Self>>(hash: H, nonce: u64) -> u128;
+}
+
+impl PoWUnit for u8 {
+    const MAX_BITS: usize = MAX_POW_BITS;
+
+    fn grind<H: DuplexHash<u8>>(mut hash: H, nonce: u64) -> u128 {
+        let mut chal_bytes = [0u8; 16];
+        hash.absorb_unchecked(&nonce.to_be_bytes())
+            .squeeze_unchecked(&mut chal_bytes);
+        u128::from_be_bytes(chal_bytes)
     }
 }
 
-impl<'a> PoWChallenge for Arthur<'a>
+/// Grinds a proof of work with an arbitrary [`DuplexHash`] `H` over units `U`,
+/// e.g. Blake3 for fast provers, or an algebraic hash for cheap recursive verification.
+///
+/// Each nonce is hashed by a fresh instance of `H` initialized with the challenge as IV.
+pub struct DuplexPoW<H, U = u8>(PhantomData<(H, U)>);
+
+impl<H, U> PoWSolver for DuplexPoW<H, U>
 where
-    Arthur<'a>: ByteReader,
+    U: PoWUnit,
+    H: DuplexHash<U>,
 {
+    const MAX_BITS: usize = max_pow_bits(U::MAX_BITS, H::SECURITY_LEVEL);
+
+    fn hash(challenge: [u8; 32], nonce: u64) -> u128 {
+        U::grind(H::new(challenge), nonce)
+    }
+}
+
+/// The number of nonces each thread tries before checking for cancellation.
+const GRIND_CHUNK: u64 = 1 << 12;
+
+/// Controls how the prover grinds proofs of work: on how many threads, and for how long.
+///
+/// The nonce space is searched in rounds of `threads` consecutive chunks of nonces,
+/// one chunk per thread; cancellation and timeouts are checked between rounds.
+/// Within a round, the first valid nonce is kept,
+/// so that the output is the first valid nonce regardless of scheduling and of the number of threads.
+#[derive(Clone)]
+pub struct Grinder<'a> {
+    threads: usize,
+    timeout: Option<Duration>,
+    cancel: Option<&'a dyn Fn() -> bool>,
+}
+
+impl Default for Grinder<'_> {
+    /// Grind on all the threads of the current rayon pool (if the `rayon` feature is enabled),
+    /// with no cancellation.
+    fn default() -> Self {
+        #[cfg(feature = "rayon")]
+        let threads = rayon::current_num_threads();
+        #[cfg(not(feature = "rayon"))]
+        let threads = 1;
+        Self {
+            threads,
+            timeout: None,
+            cancel: None,
+        }
+    }
+}
+
+impl<'a> Grinder<'a> {
+    /// Splits each round in `threads` chunks, searched in parallel on the current rayon pool.
+    #[cfg(feature = "rayon")]
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Gives up grinding after `timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Gives up grinding as soon as `cancel` returns true.
+    pub fn cancel_if(mut self, cancel: &'a dyn Fn() -> bool) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// Returns the first nonce (and its hash) that satisfies a proof of work of `bits` bits,
+    /// or `None` if grinding has been cancelled or timed out.
+    pub fn solve<S: PoWSolver + ?Sized>(
+        &self,
+        challenge: [u8; 32],
+        bits: usize,
+    ) -> Option<(u64, u128)> {
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let round = GRIND_CHUNK * self.threads as u64;
+
+        for start in (0..u64::MAX).step_by(round as usize) {
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline)
+                || self.cancel.is_some_and(|cancel| cancel())
+            {
+                return None;
+            }
+            let chunk = |i: u64| {
+                let first = start.saturating_add(i * GRIND_CHUNK);
+                S::solve_range(challenge, bits, first..first.saturating_add(GRIND_CHUNK))
+            };
+
+            #[cfg(feature = "rayon")]
+            let found = {
+                use rayon::prelude::*;
+                (0..self.threads as u64)
+                    .into_par_iter()
+                    .find_map_first(chunk)
+            };
+            #[cfg(not(feature = "rayon"))]
+            let found = (0..self.threads as u64).find_map(chunk);
+
+            if found.is_some() {
+                return found;
+            }
+        }
+
+        // Congratulations, you wasted 2^64 hash calls. You're a winner.
+        None
+    }
+}
+
+pub trait PoWChallenge {
+    /// Extension trait for generating a proof-of-work challenge, grinding with [`Keccak`].
     fn challenge_pow(&mut self, bits: usize) -> ProofResult<PoWChal> {
-        // Re-compute the challenge and store it in chal_bytes
-        let mut chal_bytes = [0u8; 16];
-        let iv = self.challenge_bytes::<32>()?;
-        let nonce = self.next_bytes::<8>()?;
-        Keccak::new(iv)
-            .absorb_unchecked(&nonce)
-            .squeeze_unchecked(&mut chal_bytes);
+        self.challenge_pow_with::<Keccak>(bits)
+    }
 
-        // Check if the challenge is valid
-        let chal = u128::from_be_bytes(chal_bytes);
-        if (chal << bits) >> bits == chal {
+    /// Generate a proof-of-work challenge, grinding with the hash `S`.
+    ///
+    /// Prover and verifier must agree on `S`, and `bits` must match the difficulty in the [`IOPattern`].
+    /// Difficulties outside of `1..=S::MAX_BITS` are rejected.
+    fn challenge_pow_with<S: PoWSolver>(&mut self, bits: usize) -> ProofResult<PoWChal>;
+}
+
+/// Prover-side proof-of-work challenges, grinding with a custom [`Grinder`].
+pub trait PoWGrind {
+    /// Generate a proof-of-work challenge, grinding with the hash `S` as configured by `grinder`.
+    ///
+    /// Fails with [`ProofError::InvalidProof`] if grinding is cancelled or times out.
+    fn challenge_pow_grind<S: PoWSolver>(
+        &mut self,
+        bits: usize,
+        grinder: &Grinder,
+    ) -> ProofResult<PoWChal>;
+}
+
+impl<H, U, R> PoWGrind for Merlin<H, U, R>
+where
+    U: Unit,
+    H: DuplexHash<U>,
+    R: RngCore + CryptoRng,
+    Merlin<H, U, R>: BytePublic + ByteChallenges + ByteWriter,
+{
+    fn challenge_pow_grind<S: PoWSolver>(
+        &mut self,
+        bits: usize,
+        grinder: &Grinder,
+    ) -> ProofResult<PoWChal> {
+        let challenge = pow_challenge::<_, S>(self, bits)?;
+        let (nonce, chal) = grinder
+            .solve::<S>(challenge, bits)
+            .ok_or(ProofError::InvalidProof)?;
+        self.add_bytes(&nonce.to_be_bytes())?;
+        Ok(PoWChal(chal))
+    }
+}
+
+impl<H, U, R> PoWChallenge for Merlin<H, U, R>
+where
+    U: Unit,
+    H: DuplexHash<U>,
+    R: RngCore + CryptoRng,
+    Merlin<H, U, R>: BytePublic + ByteChallenges + ByteWriter,
+{
+    fn challenge_pow_with<S: PoWSolver>(&mut self, bits: usize) -> ProofResult<PoWChal> {
+        self.challenge_pow_grind::<S>(bits, &Grinder::default())
+    }
+}
+
+impl<'a, H, U> PoWChallenge for Arthur<'a, H, U>
+where
+    U: Unit,
+    H: DuplexHash<U>,
+    Arthur<'a, H, U>: BytePublic + ByteChallenges + ByteReader,
+{
+    fn challenge_pow_with<S: PoWSolver>(&mut self, bits: usize) -> ProofResult<PoWChal> {
+        // Re-compute the challenge and check it against the nonce.
+        let challenge = pow_challenge::<_, S>(self, bits)?;
+        let nonce = u64::from_be_bytes(self.next_bytes::<8>()?);
+        let chal = S::hash(challenge, nonce);
+        if is_valid_pow(chal, bits) {
             Ok(PoWChal(chal))
         } else {
             Err(ProofError::InvalidProof)
@@ -87,11 +395,45 @@ where
     }
 }
 
+/// Verify the proofs of work of many independent verifiers at once, grinding with the hash `S`.
+///
+/// Each verifier re-computes its challenge and reads its nonce as in [`PoWChallenge::challenge_pow_with`],
+/// then all the nonces are hashed together with [`PoWSolver::hash_batch`].
+/// The result of each verifier is returned in order, so that an invalid proof does not affect the others.
+pub fn verify_pow_batch<S, T>(verifiers: &mut [T], bits: usize) -> Vec<ProofResult<PoWChal>>
+where
+    S: PoWSolver,
+    T: BytePublic + ByteChallenges + ByteReader,
+{
+    let inputs = verifiers
+        .iter_mut()
+        .map(|verifier| {
+            let challenge = pow_challenge::<_, S>(verifier, bits)?;
+            let nonce = u64::from_be_bytes(verifier.next_bytes::<8>()?);
+            Ok((challenge, nonce))
+        })
+        .collect::<Vec<ProofResult<_>>>();
+    let mut hashes =
+        S::hash_batch(&inputs.iter().flatten().copied().collect::<Vec<_>>()).into_iter();
+    inputs
+        .into_iter()
+        .map(|input| {
+            input?;
+            let chal = hashes.next().unwrap();
+            if is_valid_pow(chal, bits) {
+                Ok(PoWChal(chal))
+            } else {
+                Err(ProofError::InvalidProof)
+            }
+        })
+        .collect()
+}
+
 #[test]
 fn test_pow() {
-    let iopattern = IOPattern::new("the proof of work lottery 🎰")
+    let iopattern = IOPattern::<crate::DefaultHash>::new("the proof of work lottery 🎰")
         .add_bytes(1, "something")
-        .challenge_pow("rolling dices");
+        .challenge_pow(5, "rolling dices");
 
     let mut prover = iopattern.to_merlin();
     prover.add_bytes(b"\0").expect("Invalid IOPattern");
@@ -103,3 +445,198 @@ fn test_pow() {
     let got = verifier.challenge_pow(5).unwrap();
     assert_eq!(expected.0, got.0);
 }
+
+#[test]
+fn test_pow_batch_matches_sequential() {
+    let iopattern = IOPattern::<crate::DefaultHash>::new("the proof of work lottery 🎰")
+        .challenge_pow(10, "rolling dices");
+
+    let mut prover = iopattern.to_merlin();
+    let got = prover.challenge_pow(10).unwrap();
+    let nonce = u64::from_be_bytes(prover.transcript().try_into().unwrap());
+
+    // The batched search must return the first nonce found by a sequential one.
+    let mut merlin = iopattern.to_merlin();
+    merlin.public_bytes(&[10]).unwrap();
+    let challenge = merlin.challenge_bytes::<32>().unwrap();
+    let hash = Keccak::new(challenge);
+    let mut chal_bytes = [0u8; 16];
+    let expected = (0u64..)
+        .find(|nonce| {
+            hash.clone()
+                .absorb_unchecked(&nonce.to_be_bytes())
+                .squeeze_unchecked(&mut chal_bytes);
+            u128::from_be_bytes(chal_bytes).leading_zeros() >= 10
+        })
+        .unwrap();
+    assert_eq!(nonce, expected);
+    assert_eq!(got.0, u128::from_be_bytes(chal_bytes));
+}
+
+#[test]
+fn test_pow_verify_batch() {
+    let iopattern = IOPattern::<crate::DefaultHash>::new("the proof of work lottery 🎰")
+        .add_bytes(1, "something")
+        .challenge_pow(8, "rolling dices");
+
+    // Batches are not a multiple of the batch size of Keccak.
+    let mut transcripts = (0..11u8)
+        .map(|i| {
+            let mut prover = iopattern.to_merlin();
+            prover.add_bytes(&[i]).unwrap();
+            prover.challenge_pow(8).unwrap();
+            prover.transcript().to_vec()
+        })
+        .collect::<Vec<_>>();
+    // Tamper with a nonce, and truncate a transcript.
+    transcripts[3][1] ^= 1;
+    transcripts[5].pop();
+
+    let mut verifiers = transcripts
+        .iter()
+        .map(|transcript| {
+            let mut verifier = iopattern.to_arthur(transcript);
+            verifier.next_bytes::<1>().unwrap();
+            verifier
+        })
+        .collect::<Vec<_>>();
+    let batched = verify_pow_batch::<Keccak, _>(&mut verifiers, 8);
+    let valid = batched.iter().map(Result::is_ok).collect::<Vec<_>>();
+    assert_eq!(valid, (0..11).map(|i| i != 3 && i != 5).collect::<Vec<_>>());
+
+    for (transcript, batched) in transcripts.iter().zip(batched) {
+        let mut verifier = iopattern.to_arthur(transcript);
+        verifier.next_bytes::<1>().unwrap();
+        match (verifier.challenge_pow(8), batched) {
+            (Ok(expected), Ok(got)) => assert_eq!(expected.0, got.0),
+            (Err(_), Err(_)) => (),
+            _ => panic!("batched and sequential verification disagree"),
+        }
+    }
+    assert!(verify_pow_batch::<Keccak, _>(&mut verifiers[..0], 8).is_empty());
+
+    // The batched Keccak hash agrees with the sequential one.
+    let inputs = (0..11u64).map(|i| ([i as u8; 32], i)).collect::<Vec<_>>();
+    assert_eq!(
+        <Keccak as PoWSolver>::hash_batch(&inputs),
+        <DuplexPoW<Keccak> as PoWSolver>::hash_batch(&inputs)
+    );
+}
+
+#[test]
+fn test_pow_grinding_hashes() {
+    use crate::hash::xof::XofBridge;
+    use crate::plugins::small_fields::poseidon2::Poseidon2BabyBear;
+    use crate::plugins::small_fields::BabyBear;
+
+    type Blake3PoW = DuplexPoW<XofBridge<blake3::Hasher>>;
+    type Poseidon2PoW = DuplexPoW<Poseidon2BabyBear, BabyBear>;
+
+    fn check<S: PoWSolver>(bits: usize) {
+        let iopattern = IOPattern::<crate::DefaultHash>::new("the proof of work lottery 🎰")
+            .challenge_pow(bits, "rolling dices");
+        let mut prover = iopattern.to_merlin();
+        let expected = prover.challenge_pow_with::<S>(bits).unwrap();
+        assert!(expected.0.leading_zeros() as usize >= bits);
+
+        let mut verifier = iopattern.to_arthur(prover.transcript());
+        let got = verifier.challenge_pow_with::<S>(bits).unwrap();
+        assert_eq!(expected.0, got.0);
+    }
+
+    check::<Blake3PoW>(10);
+    check::<Poseidon2PoW>(10);
+    check::<DuplexPoW<Keccak>>(10);
+
+    // The generic sponge adapter agrees with the batched Keccak solver.
+    let challenge = [7u8; 32];
+    assert_eq!(
+        <DuplexPoW<Keccak> as PoWSolver>::solve(challenge, 8),
+        <Keccak as PoWSolver>::solve(challenge, 8)
+    );
+}
+
+#[test]
+fn test_pow_difficulty_is_bound() {
+    use crate::plugins::small_fields::{poseidon2::Poseidon2BabyBear, BabyBear};
+
+    let iopattern = IOPattern::<crate::DefaultHash>::new("the proof of work lottery 🎰")
+        .challenge_pow(8, "rolling dices");
+    assert_eq!(
+        iopattern.as_bytes(),
+        "the proof of work lottery 🎰\0A1rolling dices-bits\0S32rolling dices\0A8rolling dices-nonce"
+            .as_bytes()
+    );
+
+    // The challenge seeding the grinding hash depends on the difficulty.
+    let seed = |bits| pow_challenge::<_, Keccak>(&mut iopattern.to_merlin(), bits).unwrap();
+    assert_ne!(seed(8), seed(9));
+
+    let mut prover = iopattern.to_merlin();
+    let expected = prover.challenge_pow(8).unwrap();
+    let transcript = prover.transcript();
+
+    // A verifier using another difficulty derives another challenge:
+    // it rejects the proof of work, or does not output the same challenge.
+    for bits in [1, 7, 9] {
+        let mut verifier = iopattern.to_arthur(transcript);
+        assert!(!matches!(
+            verifier.challenge_pow(bits),
+            Ok(PoWChal(chal)) if chal == expected.0
+        ));
+    }
+
+    // Out-of-range difficulties are errors, not panics.
+    for bits in [0, 129, 1000] {
+        let mut verifier = iopattern.to_arthur(transcript);
+        assert!(matches!(
+            verifier.challenge_pow(bits),
+            Err(ProofError::InvalidIO(_))
+        ));
+    }
+    // Small-field grinders cannot reach more bits than the field size.
+    let mut verifier = iopattern.to_arthur(transcript);
+    assert!(matches!(
+        verifier.challenge_pow_with::<DuplexPoW<Poseidon2BabyBear, BabyBear>>(32),
+        Err(ProofError::InvalidIO(_))
+    ));
+    let mut verifier = iopattern.to_arthur(transcript);
+    assert!(verifier.challenge_pow(8).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn test_pow_iopattern_rejects_zero_bits() {
+    let _ = IOPattern::<crate::DefaultHash>::new("the proof of work lottery 🎰")
+        .challenge_pow(0, "rolling dices");
+}
+
+#[test]
+fn test_pow_grinder() {
+    let challenge = [42u8; 32];
+    let expected = <Keccak as PoWSolver>::solve_range(challenge, 12, 0..u64::MAX);
+    assert!(expected.is_some());
+
+    // The output does not depend on the number of threads.
+    assert_eq!(Grinder::default().solve::<Keccak>(challenge, 12), expected);
+    #[cfg(feature = "rayon")]
+    for threads in [1, 3, 8] {
+        let grinder = Grinder::default().threads(threads);
+        assert_eq!(grinder.solve::<Keccak>(challenge, 12), expected);
+    }
+
+    // Cancelled or timed out grinders give up.
+    let cancelled = Grinder::default().cancel_if(&|| true);
+    assert_eq!(cancelled.solve::<Keccak>(challenge, 12), None);
+    let timed_out = Grinder::default().timeout(Duration::ZERO);
+    assert_eq!(timed_out.solve::<Keccak>(challenge, 12), None);
+
+    let iopattern = IOPattern::<crate::DefaultHash>::new("the proof of work lottery 🎰")
+        .challenge_pow(64, "rolling dices");
+    let mut prover = iopattern.to_merlin();
+    let grinder = Grinder::default().timeout(Duration::from_millis(10));
+    assert!(matches!(
+        prover.challenge_pow_grind::<Keccak>(64, &grinder),
+        Err(ProofError::InvalidProof)
+    ));
+}
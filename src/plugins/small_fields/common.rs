@@ -0,0 +1,144 @@
+use rand::{CryptoRng, RngCore};
+
+use super::{FieldChallenges, FieldPublic, SmallField};
+use crate::plugins::pow::PoWUnit;
+use crate::plugins::{bytes_uniform_modp, challenge_short_integers};
+use crate::{Arthur, DuplexHash, IOPatternError, Merlin, ProofResult, UnitTranscript};
+
+// Bytes <-> Field elements interactions:
+
+impl<T, F> FieldPublic<F> for T
+where
+    F: SmallField,
+    T: UnitTranscript<u8>,
+{
+    type Repr = Vec<u8>;
+
+    fn public_scalars(&mut self, input: &[F]) -> ProofResult<Self::Repr> {
+        let mut buf = Vec::new();
+        // write never fails on Vec<u8>
+        F::write(input, &mut buf).unwrap();
+        self.public_units(&buf)?;
+        Ok(buf)
+    }
+}
+
+impl<F, T> FieldChallenges<F> for T
+where
+    F: SmallField,
+    T: UnitTranscript<u8>,
+{
+    fn fill_challenge_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        let mut buf = vec![0u8; bytes_uniform_modp(F::MODULUS_BIT_SIZE)];
+
+        for o in output.iter_mut() {
+            self.fill_challenge_units(&mut buf)?;
+            *o = F::from_be_bytes_mod_order(&buf);
+        }
+        Ok(())
+    }
+
+    fn fill_challenge_short_scalars(&mut self, output: &mut [F], bits: usize) -> ProofResult<()> {
+        let integers =
+            challenge_short_integers(self, output.len(), bits, F::MODULUS_BIT_SIZE as usize)?;
+        for (o, integer) in output.iter_mut().zip(integers) {
+            *o = F::from_be_bytes_mod_order(&integer);
+        }
+        Ok(())
+    }
+}
+
+/// Squeeze one field element per short scalar, and keep its `bits` least significant bits.
+///
+/// The result is $2^{\text{bits}} / p$-close to uniform.
+pub(super) fn fill_truncated_short_scalars<F, T>(
+    transcript: &mut T,
+    output: &mut [F],
+    bits: usize,
+) -> ProofResult<()>
+where
+    F: SmallField,
+    T: FieldChallenges<F> + ?Sized,
+{
+    if bits == 0 || bits >= F::MODULUS_BIT_SIZE as usize {
+        return Err(IOPatternError::from(format!(
+            "Short scalars must have between 1 and {} bits, got {}",
+            F::MODULUS_BIT_SIZE - 1,
+            bits
+        ))
+        .into());
+    }
+    transcript.fill_challenge_scalars(output)?;
+    for o in output.iter_mut() {
+        *o = F::from_u64_reduced(o.as_canonical_u64() & ((1 << bits) - 1));
+    }
+    Ok(())
+}
+
+// Field <-> Field interactions:
+
+impl<H, R, F> FieldPublic<F> for Merlin<H, F, R>
+where
+    F: SmallField,
+    H: DuplexHash<F>,
+    R: RngCore + CryptoRng,
+{
+    type Repr = ();
+
+    fn public_scalars(&mut self, input: &[F]) -> ProofResult<Self::Repr> {
+        self.public_units(input)?;
+        Ok(())
+    }
+}
+
+impl<H, F> FieldPublic<F> for Arthur<'_, H, F>
+where
+    F: SmallField,
+    H: DuplexHash<F>,
+{
+    type Repr = ();
+
+    fn public_scalars(&mut self, input: &[F]) -> ProofResult<Self::Repr> {
+        self.public_units(input)?;
+        Ok(())
+    }
+}
+
+impl<H, R, F> FieldChallenges<F> for Merlin<H, F, R>
+where
+    F: SmallField,
+    H: DuplexHash<F>,
+    R: RngCore + CryptoRng,
+{
+    fn fill_challenge_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        self.fill_challenge_units(output)?;
+        Ok(())
+    }
+}
+
+impl<H, F> FieldChallenges<F> for Arthur<'_, H, F>
+where
+    F: SmallField,
+    H: DuplexHash<F>,
+{
+    fn fill_challenge_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        self.fill_challenge_units(output)?;
+        Ok(())
+    }
+}
+
+// Proof of work over small fields:
+
+impl<F: SmallField> PoWUnit for F {
+    const MAX_BITS: usize = F::MODULUS_BIT_SIZE as usize;
+
+    /// The nonce is absorbed as four 16-bit limbs, so that the encoding is injective in every supported field.
+    /// The squeezed element is placed in the most significant bits of the output.
+    fn grind<H: DuplexHash<F>>(mut hash: H, nonce: u64) -> u128 {
+        let limbs: [F; 4] =
+            core::array::from_fn(|i| F::from_u64_reduced((nonce >> (48 - 16 * i)) & 0xffff));
+        let mut out = [F::ZERO];
+        hash.absorb_unchecked(&limbs).squeeze_unchecked(&mut out);
+        (out[0].as_canonical_u64() as u128) << (128 - F::MODULUS_BIT_SIZE)
+    }
+}
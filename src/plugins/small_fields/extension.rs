@@ -0,0 +1,156 @@
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use super::{BabyBear, Goldilocks, Mersenne31, SmallField};
+
+/// A field that admits an extension of degree `D`, defined by an irreducible polynomial
+/// $X^D - \sum_{i < D} c_i X^i$.
+pub trait Extendable<const D: usize>: SmallField {
+    /// The coefficients $c_i$ of the reduction rule $X^D = \sum_{i < D} c_i X^i$.
+    const REDUCTION: [Self; D];
+}
+
+/// An element of the degree-`D` extension of `F`, represented by its coefficients over the basis
+/// $1, X, \dots, X^{D-1}$.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ExtensionField<F: Extendable<D>, const D: usize>(pub [F; D]);
+
+impl<F: Extendable<D>, const D: usize> ExtensionField<F, D> {
+    pub const ZERO: Self = Self([F::ZERO; D]);
+
+    /// Embed an element of the base field.
+    pub fn from_base(x: F) -> Self {
+        let mut coeffs = [F::ZERO; D];
+        coeffs[0] = x;
+        Self(coeffs)
+    }
+
+    /// The coefficients of the element over the base field.
+    pub fn as_base_slice(&self) -> &[F] {
+        &self.0
+    }
+
+    /// The multiplicative identity.
+    pub fn one() -> Self {
+        Self::from_base(F::ONE)
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> zeroize::Zeroize for ExtensionField<F, D> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> Default for ExtensionField<F, D> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> From<[F; D]> for ExtensionField<F, D> {
+    fn from(coeffs: [F; D]) -> Self {
+        Self(coeffs)
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> Add for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> AddAssign for ExtensionField<F, D> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> Sub for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> SubAssign for ExtensionField<F, D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> Neg for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|a| -a))
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> Mul for ExtensionField<F, D> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        // Schoolbook multiplication, followed by reduction of the terms of degree >= D,
+        // starting from the highest one.
+        let mut product = vec![F::ZERO; 2 * D - 1];
+        for (i, a) in self.0.iter().enumerate() {
+            for (j, b) in rhs.0.iter().enumerate() {
+                product[i + j] += *a * *b;
+            }
+        }
+        for k in (D..2 * D - 1).rev() {
+            let top = product[k];
+            for (i, c) in F::REDUCTION.iter().enumerate() {
+                product[k - D + i] += top * *c;
+            }
+        }
+        let mut coeffs = [F::ZERO; D];
+        coeffs.copy_from_slice(&product[..D]);
+        Self(coeffs)
+    }
+}
+
+impl<F: Extendable<D>, const D: usize> MulAssign for ExtensionField<F, D> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Binomial extensions $X^D - W$.
+macro_rules! binomial_extension {
+    ($field:ident, $degree:literal, $w:literal) => {
+        impl Extendable<$degree> for $field {
+            const REDUCTION: [Self; $degree] = {
+                let mut reduction = [Self::ZERO; $degree];
+                reduction[0] = $field($w);
+                reduction
+            };
+        }
+    };
+}
+
+binomial_extension!(Goldilocks, 4, 7);
+binomial_extension!(Goldilocks, 5, 3);
+binomial_extension!(BabyBear, 4, 11);
+binomial_extension!(BabyBear, 5, 2);
+
+// Neither 4 nor 5 divide p - 1 for Mersenne31, so there is no binomial extension of these degrees.
+// We use the trinomials X^4 - X - 1 and X^5 - X - 6 instead.
+impl Extendable<4> for Mersenne31 {
+    const REDUCTION: [Self; 4] = [Mersenne31(1), Mersenne31(1), Mersenne31(0), Mersenne31(0)];
+}
+
+impl Extendable<5> for Mersenne31 {
+    const REDUCTION: [Self; 5] = [
+        Mersenne31(6),
+        Mersenne31(1),
+        Mersenne31(0),
+        Mersenne31(0),
+        Mersenne31(0),
+    ];
+}
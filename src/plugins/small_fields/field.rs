@@ -0,0 +1,226 @@
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::io;
+
+use crate::hash::Unit;
+
+/// A prime field whose modulus fits in a machine word.
+///
+/// Elements are always kept in canonical form, i.e. as integers in $[0, p)$,
+/// and are encoded on the wire as [`SmallField::BYTES`] little-endian bytes.
+pub trait SmallField:
+    'static
+    + Unit
+    + Copy
+    + Default
+    + Eq
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+    /// The modulus $p$ of the field.
+    const ORDER: u64;
+    /// The bit length of the modulus.
+    const MODULUS_BIT_SIZE: u32 = 64 - Self::ORDER.leading_zeros();
+    /// The number of bytes used to encode an element.
+    const BYTES: usize;
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Return the element `x mod p`.
+    fn from_u64_reduced(x: u64) -> Self;
+
+    /// Return the element `x`, if `x < p`.
+    fn from_canonical_u64(x: u64) -> Option<Self>;
+
+    /// Return the canonical representative of the element, in $[0, p)$.
+    fn as_canonical_u64(&self) -> u64;
+
+    /// Interpret a big-endian sequence of bytes as an integer, and reduce it mod $p$.
+    fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        bytes.iter().fold(Self::ZERO, |acc, &b| {
+            acc * Self::from_u64_reduced(256) + Self::from_u64_reduced(b as u64)
+        })
+    }
+
+    fn square(&self) -> Self {
+        *self * *self
+    }
+
+    /// Exponentiation by square-and-multiply.
+    fn pow(&self, mut exp: u64) -> Self {
+        let mut base = *self;
+        let mut acc = Self::ONE;
+        while exp != 0 {
+            if exp & 1 == 1 {
+                acc *= base;
+            }
+            base = base.square();
+            exp >>= 1;
+        }
+        acc
+    }
+
+    /// The multiplicative inverse of the element, if non-zero.
+    fn inverse(&self) -> Option<Self> {
+        (*self != Self::ZERO).then(|| self.pow(Self::ORDER - 2))
+    }
+}
+
+macro_rules! small_field {
+    ($(#[$attr:meta])* $name:ident, $repr:ty, $modulus:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, zeroize::Zeroize)]
+        pub struct $name(pub(super) $repr);
+
+        impl SmallField for $name {
+            const ORDER: u64 = $modulus;
+            const BYTES: usize = core::mem::size_of::<$repr>();
+            const ZERO: Self = Self(0);
+            const ONE: Self = Self(1);
+
+            #[inline]
+            fn from_u64_reduced(x: u64) -> Self {
+                Self((x % Self::ORDER) as $repr)
+            }
+
+            #[inline]
+            fn from_canonical_u64(x: u64) -> Option<Self> {
+                (x < Self::ORDER).then(|| Self(x as $repr))
+            }
+
+            #[inline]
+            fn as_canonical_u64(&self) -> u64 {
+                self.0 as u64
+            }
+        }
+
+        impl Unit for $name {
+            fn write(bunch: &[Self], w: &mut impl io::Write) -> Result<(), io::Error> {
+                for x in bunch {
+                    w.write_all(&x.0.to_le_bytes())?;
+                }
+                Ok(())
+            }
+
+            fn read(r: &mut impl io::Read, bunch: &mut [Self]) -> Result<(), io::Error> {
+                let mut buf = [0u8; core::mem::size_of::<$repr>()];
+                for x in bunch.iter_mut() {
+                    r.read_exact(&mut buf)?;
+                    *x = Self::from_canonical_u64(<$repr>::from_le_bytes(buf) as u64).ok_or_else(
+                        || io::Error::new(io::ErrorKind::InvalidData, "Non-canonical field element."),
+                    )?;
+                }
+                Ok(())
+            }
+        }
+
+        impl From<u32> for $name {
+            fn from(x: u32) -> Self {
+                Self::from_u64_reduced(x as u64)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        impl Add for $name {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                // For Goldilocks, the sum of two canonical elements may overflow 64 bits.
+                let sum = self.0 as u128 + rhs.0 as u128;
+                let order = Self::ORDER as u128;
+                Self((if sum >= order { sum - order } else { sum }) as $repr)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self + (-rhs)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                if self.0 == 0 {
+                    self
+                } else {
+                    Self((Self::ORDER - self.0 as u64) as $repr)
+                }
+            }
+        }
+
+        impl Mul for $name {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                let product = self.0 as u128 * rhs.0 as u128;
+                Self((product % Self::ORDER as u128) as $repr)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $name {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+    };
+}
+
+small_field!(
+    /// The Goldilocks field, of order $p = 2^{64} - 2^{32} + 1$.
+    Goldilocks,
+    u64,
+    0xffff_ffff_0000_0001
+);
+
+small_field!(
+    /// The BabyBear field, of order $p = 2^{31} - 2^{27} + 1$.
+    BabyBear,
+    u32,
+    0x7800_0001
+);
+
+small_field!(
+    /// The Mersenne31 field, of order $p = 2^{31} - 1$.
+    Mersenne31,
+    u32,
+    0x7fff_ffff
+);
@@ -0,0 +1,34 @@
+use super::*;
+use crate::plugins::{bytes_short_scalar, bytes_uniform_modp};
+
+impl<F, H> FieldIOPattern<F> for IOPattern<H>
+where
+    F: SmallField,
+    H: DuplexHash,
+{
+    fn add_scalars(self, count: usize, label: &str) -> Self {
+        self.add_bytes(count * F::BYTES, label)
+    }
+
+    fn challenge_scalars(self, count: usize, label: &str) -> Self {
+        self.challenge_bytes(count * bytes_uniform_modp(F::MODULUS_BIT_SIZE), label)
+    }
+
+    fn challenge_short_scalars(self, count: usize, bits: usize, label: &str) -> Self {
+        self.challenge_bytes(count * bytes_short_scalar(bits), label)
+    }
+}
+
+impl<F, H> FieldIOPattern<F> for IOPattern<H, F>
+where
+    F: SmallField,
+    H: DuplexHash<F>,
+{
+    fn add_scalars(self, count: usize, label: &str) -> Self {
+        self.absorb(count, label)
+    }
+
+    fn challenge_scalars(self, count: usize, label: &str) -> Self {
+        self.squeeze(count, label)
+    }
+}
@@ -0,0 +1,120 @@
+//! This module contains native support for the small prime fields used in STARK provers,
+//! namely Goldilocks, BabyBear, and Mersenne31, without depending on arkworks or zkcrypto.
+//!
+//! It provides:
+//! - the [`Unit`][`crate::Unit`] types [`Goldilocks`][`small_fields::Goldilocks`],
+//!   [`BabyBear`][`small_fields::BabyBear`], and [`Mersenne31`][`small_fields::Mersenne31`];
+//! - sponges operating natively over those fields, built from the [Poseidon2](https://eprint.iacr.org/2023/323)
+//!   and [Monolith](https://eprint.iacr.org/2023/1025) permutations via [`DuplexSponge`][`crate::hash::sponge::DuplexSponge`];
+//! - extension traits to absorb and squeeze field elements, and to squeeze challenges
+//!   in the degree-4 and degree-5 extensions of those fields.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use nimue::plugins::small_fields::*;
+//! use nimue::plugins::small_fields::poseidon2::Poseidon2BabyBear;
+//!
+//! type Ext = ExtensionField<BabyBear, 4>;
+//!
+//! let io = IOPattern::<Poseidon2BabyBear, BabyBear>::new("small fields 🐻");
+//! let io = FieldIOPattern::<BabyBear>::add_scalars(io, 2, "commitment");
+//! let io = ExtensionIOPattern::<BabyBear, 4>::challenge_extension_scalars(io, 1, "alpha");
+//!
+//! let mut merlin = io.to_merlin();
+//! merlin.add_scalars(&[BabyBear::from(1u32), BabyBear::from(2u32)]).unwrap();
+//! let [alpha]: [Ext; 1] = merlin.challenge_extension_scalars().unwrap();
+//!
+//! let mut arthur = io.to_arthur(merlin.transcript());
+//! let commitment: [BabyBear; 2] = arthur.next_scalars().unwrap();
+//! assert_eq!(commitment, [BabyBear::from(1u32), BabyBear::from(2u32)]);
+//! let [alpha_verifier]: [Ext; 1] = arthur.challenge_extension_scalars().unwrap();
+//! assert_eq!(alpha, alpha_verifier);
+//! ```
+//!
+//! The same traits are implemented for byte-oriented sponges such as [`Keccak`][`crate::hash::Keccak`].
+
+/// Bridging small fields with the sponge units.
+mod common;
+/// Extensions of small fields.
+mod extension;
+/// The small prime fields.
+mod field;
+/// IO Pattern utilities.
+mod iopattern;
+/// Support for the Monolith hash function.
+pub mod monolith;
+/// Support for the Poseidon2 hash function.
+pub mod poseidon2;
+/// Veririfer's utilities for decoding a transcript.
+mod reader;
+/// Prover's utilities for encoding into a transcript.
+mod writer;
+
+#[cfg(test)]
+/// Tests for small fields.
+mod tests;
+
+pub use crate::traits::*;
+pub use crate::{hash::Unit, Arthur, DuplexHash, IOPattern, Merlin, ProofError, ProofResult, Safe};
+pub use extension::{Extendable, ExtensionField};
+pub use field::{BabyBear, Goldilocks, Mersenne31, SmallField};
+
+super::traits::field_traits!(SmallField, short_scalars: common::fill_truncated_short_scalars);
+
+/// Squeeze elements of the degree-`D` extension of `F` in the IO pattern.
+pub trait ExtensionIOPattern<F: Extendable<D>, const D: usize> {
+    fn challenge_extension_scalars(self, count: usize, label: &str) -> Self;
+}
+
+/// Interpret verifier messages as uniformly distributed elements of the degree-`D` extension of `F`.
+///
+/// Each extension element is sampled as `D` independent challenges in the base field.
+pub trait ExtensionChallenges<F: Extendable<D>, const D: usize> {
+    fn fill_challenge_extension_scalars(
+        &mut self,
+        output: &mut [ExtensionField<F, D>],
+    ) -> ProofResult<()>;
+
+    fn challenge_extension_scalars<const N: usize>(
+        &mut self,
+    ) -> ProofResult<[ExtensionField<F, D>; N]> {
+        let mut output = [ExtensionField::default(); N];
+        self.fill_challenge_extension_scalars(&mut output)
+            .map(|()| output)
+    }
+}
+
+impl<F, T, const D: usize> ExtensionIOPattern<F, D> for T
+where
+    F: Extendable<D>,
+    T: FieldIOPattern<F>,
+{
+    fn challenge_extension_scalars(self, count: usize, label: &str) -> Self {
+        self.challenge_scalars(count * D, label)
+    }
+}
+
+impl<F, T, const D: usize> ExtensionChallenges<F, D> for T
+where
+    F: Extendable<D>,
+    T: FieldChallenges<F>,
+{
+    fn fill_challenge_extension_scalars(
+        &mut self,
+        output: &mut [ExtensionField<F, D>],
+    ) -> ProofResult<()> {
+        for o in output.iter_mut() {
+            self.fill_challenge_scalars(&mut o.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// Write the 32-byte `iv` over the capacity of a sponge, splitting it evenly among its elements.
+fn write_iv<F: SmallField>(capacity: &mut [F], iv: [u8; 32]) {
+    let chunk_size = iv.len().div_ceil(capacity.len());
+    for (x, chunk) in capacity.iter_mut().zip(iv.chunks(chunk_size)) {
+        *x = F::from_be_bytes_mod_order(chunk);
+    }
+}
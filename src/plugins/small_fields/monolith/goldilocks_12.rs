@@ -0,0 +1,82 @@
+//! Generated with `scripts/monolith_constants.py`.
+use crate::plugins::small_fields::Goldilocks;
+
+pub type Field = Goldilocks;
+pub const ROUNDS: usize = 6;
+pub const N: usize = 12;
+pub const R: usize = 8;
+
+pub const MDS_ROW: [u64; 12] = [7, 23, 8, 26, 13, 10, 9, 7, 6, 22, 21, 8];
+
+pub const ROUND_CONSTANTS: &[[Goldilocks; 12]] = &[
+    [
+        Goldilocks(13476354041962929241),
+        Goldilocks(2477472256560896721),
+        Goldilocks(14483275165201534048),
+        Goldilocks(5792803393827580017),
+        Goldilocks(6149368453780483483),
+        Goldilocks(11634042734860368628),
+        Goldilocks(12881351614336805688),
+        Goldilocks(8402706021345706416),
+        Goldilocks(1075015613636214564),
+        Goldilocks(13908411378960722897),
+        Goldilocks(10151206901843499092),
+        Goldilocks(6394515805373007458),
+    ],
+    [
+        Goldilocks(3114920554315813389),
+        Goldilocks(8394326509632738002),
+        Goldilocks(153199458926756473),
+        Goldilocks(5579228890784932442),
+        Goldilocks(12864767096591267927),
+        Goldilocks(5676616501821946070),
+        Goldilocks(69975414332808522),
+        Goldilocks(1505553882264328589),
+        Goldilocks(11785489005977899437),
+        Goldilocks(15635491157050832122),
+        Goldilocks(6969579502078877524),
+        Goldilocks(3689763473602160579),
+    ],
+    [
+        Goldilocks(1634773587744289753),
+        Goldilocks(9403511246265657721),
+        Goldilocks(10731157264979795746),
+        Goldilocks(14389525486858512608),
+        Goldilocks(2438736229787161010),
+        Goldilocks(6628899614401683566),
+        Goldilocks(12822466467588113497),
+        Goldilocks(11957776013121867289),
+        Goldilocks(661274149733829322),
+        Goldilocks(18139186355860197893),
+        Goldilocks(13897428152170013244),
+        Goldilocks(5702764986677347509),
+    ],
+    [
+        Goldilocks(8547950410218494321),
+        Goldilocks(11277378911921822438),
+        Goldilocks(2195068139080132519),
+        Goldilocks(5168940269494021551),
+        Goldilocks(14761601543394815188),
+        Goldilocks(11761739599605670016),
+        Goldilocks(7332897708222250470),
+        Goldilocks(14717257006281009243),
+        Goldilocks(9690200457205622860),
+        Goldilocks(1392649540902787429),
+        Goldilocks(14898232272713243073),
+        Goldilocks(2915015007353656934),
+    ],
+    [
+        Goldilocks(2499613964419054382),
+        Goldilocks(4157193522190076848),
+        Goldilocks(13149484654335027164),
+        Goldilocks(10426155290609304484),
+        Goldilocks(1325903284993574958),
+        Goldilocks(11102158755273998375),
+        Goldilocks(5234471546066232500),
+        Goldilocks(10385984222616367748),
+        Goldilocks(5376698001926876430),
+        Goldilocks(13717732972444477432),
+        Goldilocks(15258283360068679607),
+        Goldilocks(4780825487178987109),
+    ],
+];
@@ -0,0 +1,105 @@
+//! Generated with `scripts/monolith_constants.py`.
+use crate::plugins::small_fields::Mersenne31;
+
+pub type Field = Mersenne31;
+pub const ROUNDS: usize = 6;
+pub const N: usize = 16;
+pub const R: usize = 8;
+
+pub const MDS_ROW: [u64; 16] = [
+    61402, 17845, 26798, 59689, 12021, 40901, 41351, 27521, 56951, 12034, 53865, 43244, 7454,
+    33823, 28750, 1108,
+];
+
+pub const ROUND_CONSTANTS: &[[Mersenne31; 16]] = &[
+    [
+        Mersenne31(4677167),
+        Mersenne31(1406986118),
+        Mersenne31(760364135),
+        Mersenne31(804323346),
+        Mersenne31(933845747),
+        Mersenne31(1339684572),
+        Mersenne31(1878944062),
+        Mersenne31(209128334),
+        Mersenne31(1621044775),
+        Mersenne31(1274820408),
+        Mersenne31(1148462851),
+        Mersenne31(1662018025),
+        Mersenne31(597692939),
+        Mersenne31(508468195),
+        Mersenne31(1136728893),
+        Mersenne31(1889018341),
+    ],
+    [
+        Mersenne31(1171595176),
+        Mersenne31(778472038),
+        Mersenne31(1624361708),
+        Mersenne31(376283736),
+        Mersenne31(551003711),
+        Mersenne31(1038750345),
+        Mersenne31(1727917440),
+        Mersenne31(671803158),
+        Mersenne31(269610543),
+        Mersenne31(1431239720),
+        Mersenne31(1063422652),
+        Mersenne31(171796897),
+        Mersenne31(248099302),
+        Mersenne31(758952916),
+        Mersenne31(2029753197),
+        Mersenne31(949862315),
+    ],
+    [
+        Mersenne31(1308986532),
+        Mersenne31(186536998),
+        Mersenne31(532909100),
+        Mersenne31(1695040977),
+        Mersenne31(2101864778),
+        Mersenne31(2015222117),
+        Mersenne31(1185598010),
+        Mersenne31(885156323),
+        Mersenne31(671938362),
+        Mersenne31(1584691421),
+        Mersenne31(2084802339),
+        Mersenne31(794549820),
+        Mersenne31(706116895),
+        Mersenne31(1345989023),
+        Mersenne31(316435924),
+        Mersenne31(325243121),
+    ],
+    [
+        Mersenne31(1434451699),
+        Mersenne31(568005852),
+        Mersenne31(1285188415),
+        Mersenne31(39689584),
+        Mersenne31(2047730969),
+        Mersenne31(199714398),
+        Mersenne31(154665772),
+        Mersenne31(1379258642),
+        Mersenne31(753919814),
+        Mersenne31(723037399),
+        Mersenne31(1122528312),
+        Mersenne31(1353800289),
+        Mersenne31(845010153),
+        Mersenne31(1631837064),
+        Mersenne31(1550170712),
+        Mersenne31(949350024),
+    ],
+    [
+        Mersenne31(197220668),
+        Mersenne31(1918786623),
+        Mersenne31(2095540634),
+        Mersenne31(247528064),
+        Mersenne31(155713658),
+        Mersenne31(1848401356),
+        Mersenne31(399295532),
+        Mersenne31(446522184),
+        Mersenne31(1072569757),
+        Mersenne31(307380170),
+        Mersenne31(1965189705),
+        Mersenne31(1996232748),
+        Mersenne31(591633855),
+        Mersenne31(1760063261),
+        Mersenne31(1751971580),
+        Mersenne31(1554205973),
+    ],
+];
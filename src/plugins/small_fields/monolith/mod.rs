@@ -0,0 +1,172 @@
+//! The [Monolith](https://eprint.iacr.org/2023/1025) permutation over Goldilocks and Mersenne31.
+//!
+//! After an initial application of the linear layer (*Concrete*), each round applies
+//! the lookup-based S-boxes on the first elements of the state (*Bars*),
+//! a quadratic Feistel layer (*Bricks*), the linear layer, and adds the round constants
+//! (except for the last round).
+use super::{Goldilocks, Mersenne31, SmallField};
+use crate::hash::sponge::{algebraic_security_level, Sponge};
+
+/// A field over which the *Bars* layer of Monolith is defined.
+pub trait MonolithField: SmallField {
+    /// The number of state elements to which the *Bars* layer is applied.
+    const BARS: usize;
+
+    /// Decompose the element into limbs, apply the S-boxes to each limb, and recompose it.
+    fn bar(self) -> Self;
+}
+
+/// The 8-bit S-box $y \mapsto (y \oplus (\bar{y} \lll 1) (y \lll 2) (y \lll 3)) \lll 1$.
+#[inline]
+fn s_box(y: u8) -> u8 {
+    let chi = !y.rotate_left(1) & y.rotate_left(2) & y.rotate_left(3);
+    (y ^ chi).rotate_left(1)
+}
+
+/// The 7-bit S-box $y \mapsto (y \oplus (\bar{y} \lll 1) (y \lll 2)) \lll 1$.
+#[inline]
+fn final_s_box(y: u8) -> u8 {
+    let rotl = |y: u8, r: u32| ((y << r) | (y >> (7 - r))) & 0x7f;
+    let chi = !rotl(y, 1) & rotl(y, 2) & 0x7f;
+    rotl(y ^ chi, 1)
+}
+
+impl MonolithField for Goldilocks {
+    const BARS: usize = 4;
+
+    fn bar(self) -> Self {
+        // The S-box fixes 0x00 and 0xff, hence the result is again smaller than p.
+        let limbs = self.0.to_le_bytes().map(s_box);
+        Self(u64::from_le_bytes(limbs))
+    }
+}
+
+impl MonolithField for Mersenne31 {
+    const BARS: usize = 8;
+
+    fn bar(self) -> Self {
+        // Limbs of 8, 8, 8, and 7 bits. The S-boxes fix the all-one limbs, hence the result is again smaller than p.
+        let mut limbs = self.0.to_le_bytes();
+        limbs[..3].iter_mut().for_each(|limb| *limb = s_box(*limb));
+        limbs[3] = final_s_box(limbs[3]);
+        Self(u32::from_le_bytes(limbs))
+    }
+}
+
+#[derive(Clone)]
+pub struct MonolithState<F: MonolithField, const R: usize, const N: usize> {
+    /// Number of rounds.
+    pub rounds: usize,
+    /// First row of the circulant matrix used in the linear layer.
+    pub mds_row: [F; N],
+    /// Round constants, added at the end of every round but the last one.
+    /// They are indexed by `round_constants[round_num][state_element_index]`
+    pub round_constants: &'static [[F; N]],
+
+    /// Sponge state
+    pub state: [F; N],
+}
+
+pub type MonolithHash<F, const R: usize, const N: usize> =
+    crate::hash::sponge::DuplexSponge<MonolithState<F, R, N>>;
+
+impl<F: MonolithField, const R: usize, const N: usize> AsRef<[F]> for MonolithState<F, R, N> {
+    fn as_ref(&self) -> &[F] {
+        &self.state
+    }
+}
+
+impl<F: MonolithField, const R: usize, const N: usize> AsMut<[F]> for MonolithState<F, R, N> {
+    fn as_mut(&mut self) -> &mut [F] {
+        &mut self.state
+    }
+}
+
+impl<F: MonolithField, const R: usize, const N: usize> MonolithState<F, R, N> {
+    fn apply_bars(state: &mut [F; N]) {
+        state[..F::BARS].iter_mut().for_each(|x| *x = x.bar());
+    }
+
+    fn apply_bricks(state: &mut [F; N]) {
+        for i in (1..N).rev() {
+            let square = state[i - 1].square();
+            state[i] += square;
+        }
+    }
+
+    fn apply_concrete(&self, state: &mut [F; N]) {
+        let mut new_state = [F::ZERO; N];
+        for (i, new_elem) in new_state.iter_mut().enumerate() {
+            for (j, state_elem) in state.iter().enumerate() {
+                *new_elem += *state_elem * self.mds_row[(N + j - i) % N];
+            }
+        }
+        *state = new_state;
+    }
+}
+
+impl<F: MonolithField, const R: usize, const N: usize> zeroize::Zeroize for MonolithState<F, R, N> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl<F, const R: usize, const N: usize> Sponge for MonolithState<F, R, N>
+where
+    MonolithState<F, R, N>: Default,
+    F: MonolithField,
+{
+    type U = F;
+    const N: usize = N;
+    const R: usize = R;
+    const SECURITY_LEVEL: usize = algebraic_security_level(N - R, F::MODULUS_BIT_SIZE);
+
+    fn new(iv: [u8; 32]) -> Self {
+        assert!(N >= F::BARS);
+        let mut sponge = Self::default();
+        super::write_iv(&mut sponge.state[R..], iv);
+        sponge
+    }
+
+    fn permute(&mut self) {
+        let mut state = self.state;
+        self.apply_concrete(&mut state);
+        for round in 0..self.rounds {
+            Self::apply_bars(&mut state);
+            Self::apply_bricks(&mut state);
+            self.apply_concrete(&mut state);
+            if round < self.rounds - 1 {
+                for (x, c) in state.iter_mut().zip(&self.round_constants[round]) {
+                    *x += *c;
+                }
+            }
+        }
+        self.state = state;
+    }
+}
+
+/// Initialization of constants.
+macro_rules! monolith_sponge {
+    ($name: ident, $path: tt) => {
+        pub type $name = crate::hash::sponge::DuplexSponge<
+            MonolithState<$path::Field, { $path::R }, { $path::N }>,
+        >;
+
+        impl Default for MonolithState<$path::Field, { $path::R }, { $path::N }> {
+            fn default() -> Self {
+                Self {
+                    rounds: $path::ROUNDS,
+                    mds_row: $path::MDS_ROW.map(<$path::Field as SmallField>::from_u64_reduced),
+                    round_constants: $path::ROUND_CONSTANTS,
+                    state: [<$path::Field as SmallField>::ZERO; $path::N],
+                }
+            }
+        }
+    };
+}
+
+mod goldilocks_12;
+mod mersenne31_16;
+
+monolith_sponge!(MonolithGoldilocks, goldilocks_12);
+monolith_sponge!(MonolithMersenne31, mersenne31_16);
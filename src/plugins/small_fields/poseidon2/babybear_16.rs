@@ -0,0 +1,191 @@
+//! Generated with `scripts/poseidon2_constants.py`.
+use crate::plugins::small_fields::BabyBear;
+
+pub type Field = BabyBear;
+pub const ALPHA: u64 = 7;
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 13;
+pub const N: usize = 16;
+pub const R: usize = 8;
+
+pub const EXTERNAL_CONSTANTS: &[[BabyBear; 16]] = &[
+    [
+        BabyBear(1774958255),
+        BabyBear(1185780729),
+        BabyBear(1621102414),
+        BabyBear(1796380621),
+        BabyBear(588815102),
+        BabyBear(1932426223),
+        BabyBear(1925334750),
+        BabyBear(747903232),
+        BabyBear(89648862),
+        BabyBear(360728943),
+        BabyBear(977184635),
+        BabyBear(1425273457),
+        BabyBear(256487465),
+        BabyBear(1200041953),
+        BabyBear(572403254),
+        BabyBear(448208942),
+    ],
+    [
+        BabyBear(1215789478),
+        BabyBear(944884184),
+        BabyBear(953948096),
+        BabyBear(547326025),
+        BabyBear(646827752),
+        BabyBear(889997530),
+        BabyBear(1536873262),
+        BabyBear(86189867),
+        BabyBear(1065944411),
+        BabyBear(32019634),
+        BabyBear(333311454),
+        BabyBear(456061748),
+        BabyBear(1963448500),
+        BabyBear(1827584334),
+        BabyBear(1391160226),
+        BabyBear(1348741381),
+    ],
+    [
+        BabyBear(88424255),
+        BabyBear(104111868),
+        BabyBear(1763866748),
+        BabyBear(79691676),
+        BabyBear(1988915530),
+        BabyBear(1050669594),
+        BabyBear(359890076),
+        BabyBear(573163527),
+        BabyBear(222820492),
+        BabyBear(159256268),
+        BabyBear(669703072),
+        BabyBear(763177444),
+        BabyBear(889367200),
+        BabyBear(256335831),
+        BabyBear(704371273),
+        BabyBear(25886717),
+    ],
+    [
+        BabyBear(51754520),
+        BabyBear(1833211857),
+        BabyBear(454499742),
+        BabyBear(1384520381),
+        BabyBear(777848065),
+        BabyBear(1053320300),
+        BabyBear(1851729162),
+        BabyBear(344647910),
+        BabyBear(401996362),
+        BabyBear(1046925956),
+        BabyBear(5351995),
+        BabyBear(1212119315),
+        BabyBear(754867989),
+        BabyBear(36972490),
+        BabyBear(751272725),
+        BabyBear(506915399),
+    ],
+    [
+        BabyBear(913094636),
+        BabyBear(241382878),
+        BabyBear(1578333386),
+        BabyBear(1228732114),
+        BabyBear(805189379),
+        BabyBear(433929041),
+        BabyBear(1029998479),
+        BabyBear(1056194280),
+        BabyBear(1685208700),
+        BabyBear(1340424586),
+        BabyBear(259745565),
+        BabyBear(1060821900),
+        BabyBear(405863817),
+        BabyBear(1528116846),
+        BabyBear(1017716813),
+        BabyBear(345828924),
+    ],
+    [
+        BabyBear(1417394521),
+        BabyBear(1298810016),
+        BabyBear(1997935306),
+        BabyBear(1062984998),
+        BabyBear(597940612),
+        BabyBear(386598845),
+        BabyBear(1512722580),
+        BabyBear(1853778100),
+        BabyBear(524059424),
+        BabyBear(268155062),
+        BabyBear(1589539906),
+        BabyBear(1174390528),
+        BabyBear(1691482038),
+        BabyBear(456177249),
+        BabyBear(1483646208),
+        BabyBear(1345393149),
+    ],
+    [
+        BabyBear(1924406519),
+        BabyBear(1684581733),
+        BabyBear(1690768305),
+        BabyBear(1158356173),
+        BabyBear(555638824),
+        BabyBear(806031788),
+        BabyBear(1948411476),
+        BabyBear(1446021700),
+        BabyBear(1520786250),
+        BabyBear(1391639706),
+        BabyBear(436694608),
+        BabyBear(1589192852),
+        BabyBear(331001711),
+        BabyBear(1293530661),
+        BabyBear(819090071),
+        BabyBear(1391463443),
+    ],
+    [
+        BabyBear(711414743),
+        BabyBear(1086183646),
+        BabyBear(581745560),
+        BabyBear(2011436628),
+        BabyBear(598436623),
+        BabyBear(103409622),
+        BabyBear(962047),
+        BabyBear(1691127425),
+        BabyBear(911196178),
+        BabyBear(1137461453),
+        BabyBear(1629204265),
+        BabyBear(1220409384),
+        BabyBear(445296751),
+        BabyBear(288286176),
+        BabyBear(57541084),
+        BabyBear(403727924),
+    ],
+];
+
+pub const INTERNAL_CONSTANTS: &[BabyBear] = &[
+    BabyBear(1518359488),
+    BabyBear(1990744480),
+    BabyBear(340311124),
+    BabyBear(247083962),
+    BabyBear(494676004),
+    BabyBear(481896934),
+    BabyBear(383594057),
+    BabyBear(227454140),
+    BabyBear(667524710),
+    BabyBear(1259573942),
+    BabyBear(47205229),
+    BabyBear(494719588),
+    BabyBear(260778324),
+];
+
+pub const INTERNAL_DIAGONAL: [BabyBear; 16] = [
+    BabyBear(1186390856),
+    BabyBear(879765170),
+    BabyBear(536600902),
+    BabyBear(1726809193),
+    BabyBear(1028663043),
+    BabyBear(1500152795),
+    BabyBear(323607683),
+    BabyBear(788488403),
+    BabyBear(957565389),
+    BabyBear(1172810353),
+    BabyBear(1106973921),
+    BabyBear(154226763),
+    BabyBear(412009381),
+    BabyBear(1145480645),
+    BabyBear(1227691743),
+    BabyBear(915601422),
+];
@@ -0,0 +1,164 @@
+//! Generated with `scripts/poseidon2_constants.py`.
+use crate::plugins::small_fields::Goldilocks;
+
+pub type Field = Goldilocks;
+pub const ALPHA: u64 = 7;
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 22;
+pub const N: usize = 12;
+pub const R: usize = 8;
+
+pub const EXTERNAL_CONSTANTS: &[[Goldilocks; 12]] = &[
+    [
+        Goldilocks(1431286215153372998),
+        Goldilocks(3509349009260703107),
+        Goldilocks(2289575380984896342),
+        Goldilocks(10625215922958251110),
+        Goldilocks(17137022507167291684),
+        Goldilocks(17143426961497010024),
+        Goldilocks(9589775313463224365),
+        Goldilocks(7736066733515538648),
+        Goldilocks(2217569167061322248),
+        Goldilocks(10394930802584583083),
+        Goldilocks(4612393375016695705),
+        Goldilocks(5332470884919453534),
+    ],
+    [
+        Goldilocks(8724526834049581439),
+        Goldilocks(17673787971454860688),
+        Goldilocks(2519987773101056005),
+        Goldilocks(7999687124137420323),
+        Goldilocks(18312454652563306701),
+        Goldilocks(15136091233824155669),
+        Goldilocks(1257110570403430003),
+        Goldilocks(5665449074466664773),
+        Goldilocks(16178737609685266571),
+        Goldilocks(52855143527893348),
+        Goldilocks(8084454992943870230),
+        Goldilocks(2597062441266647183),
+    ],
+    [
+        Goldilocks(3342624911463171251),
+        Goldilocks(6781356195391537436),
+        Goldilocks(4697929572322733707),
+        Goldilocks(4179687232228901671),
+        Goldilocks(17841073646522133059),
+        Goldilocks(18340176721233187897),
+        Goldilocks(13152929999122219197),
+        Goldilocks(6306257051437840427),
+        Goldilocks(4974451914008050921),
+        Goldilocks(11258703678970285201),
+        Goldilocks(581736081259960204),
+        Goldilocks(18323286026903235604),
+    ],
+    [
+        Goldilocks(10250026231324330997),
+        Goldilocks(13321947507807660157),
+        Goldilocks(13020725208899496943),
+        Goldilocks(11416990495425192684),
+        Goldilocks(7221795794796219413),
+        Goldilocks(2607917872900632985),
+        Goldilocks(2591896057192169329),
+        Goldilocks(10485489452304998145),
+        Goldilocks(9480186048908910015),
+        Goldilocks(2645141845409940474),
+        Goldilocks(16242299839765162610),
+        Goldilocks(12203738590896308135),
+    ],
+    [
+        Goldilocks(10552448846206288151),
+        Goldilocks(14987673924494666433),
+        Goldilocks(18035303280469462414),
+        Goldilocks(16595113834715919465),
+        Goldilocks(15208661533916677630),
+        Goldilocks(4170608138187333497),
+        Goldilocks(16304084357983152470),
+        Goldilocks(2331503858766652994),
+        Goldilocks(8776079357547932587),
+        Goldilocks(18299646478835171989),
+        Goldilocks(3681263166902989193),
+        Goldilocks(12612029705709390274),
+    ],
+    [
+        Goldilocks(12014669431902405777),
+        Goldilocks(11319504285297576766),
+        Goldilocks(5234999940078631477),
+        Goldilocks(1125448944938006422),
+        Goldilocks(2164405204907480972),
+        Goldilocks(6168495504522907053),
+        Goldilocks(6250236942243891229),
+        Goldilocks(18269902991411124149),
+        Goldilocks(9426885685329917236),
+        Goldilocks(4521800374915508165),
+        Goldilocks(2213719649464492152),
+        Goldilocks(9422759956003735939),
+    ],
+    [
+        Goldilocks(12723275943377720767),
+        Goldilocks(14785736031955679545),
+        Goldilocks(15257683393549924851),
+        Goldilocks(14586462537439744229),
+        Goldilocks(13109892360729616102),
+        Goldilocks(18054952537889795742),
+        Goldilocks(12589969976105374274),
+        Goldilocks(1436163932748701916),
+        Goldilocks(14879322534176465619),
+        Goldilocks(17580838042056220468),
+        Goldilocks(17970300042937392952),
+        Goldilocks(1420156878331078790),
+    ],
+    [
+        Goldilocks(17310902395782251544),
+        Goldilocks(9021117459098865178),
+        Goldilocks(9956374953785489337),
+        Goldilocks(9283926179170577664),
+        Goldilocks(2866744588122882663),
+        Goldilocks(12613310502798528952),
+        Goldilocks(48642999969593367),
+        Goldilocks(5069344854700671784),
+        Goldilocks(17704314310866354161),
+        Goldilocks(15988800480645163458),
+        Goldilocks(5818851986787837003),
+        Goldilocks(2578102338873304736),
+    ],
+];
+
+pub const INTERNAL_CONSTANTS: &[Goldilocks] = &[
+    Goldilocks(5395176197344543510),
+    Goldilocks(4555032575628627551),
+    Goldilocks(10887434669785806501),
+    Goldilocks(12415218859476220947),
+    Goldilocks(323544930728360053),
+    Goldilocks(13821005335130766955),
+    Goldilocks(8112988184280322821),
+    Goldilocks(16331987863400672412),
+    Goldilocks(16093634485870170562),
+    Goldilocks(2038954051047328382),
+    Goldilocks(17917233579925756683),
+    Goldilocks(5480704578777097169),
+    Goldilocks(741424706267005090),
+    Goldilocks(7872218736019578342),
+    Goldilocks(940187017142450256),
+    Goldilocks(3242363036477934858),
+    Goldilocks(15415646525902701306),
+    Goldilocks(10673647621461954174),
+    Goldilocks(10043771903993878423),
+    Goldilocks(5731056810399963425),
+    Goldilocks(12331335364636844807),
+    Goldilocks(6376995477333092352),
+];
+
+pub const INTERNAL_DIAGONAL: [Goldilocks; 12] = [
+    Goldilocks(13492936171842760337),
+    Goldilocks(2637570150334709262),
+    Goldilocks(6503699402311440213),
+    Goldilocks(690692014115307898),
+    Goldilocks(17940354212370006814),
+    Goldilocks(9721735900822534012),
+    Goldilocks(15721677653151720627),
+    Goldilocks(2164023303142767777),
+    Goldilocks(9243908665669225187),
+    Goldilocks(12528378416292576944),
+    Goldilocks(13815874764874571408),
+    Goldilocks(14136962050644213920),
+];
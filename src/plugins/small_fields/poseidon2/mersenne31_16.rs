@@ -0,0 +1,192 @@
+//! Generated with `scripts/poseidon2_constants.py`.
+use crate::plugins::small_fields::Mersenne31;
+
+pub type Field = Mersenne31;
+pub const ALPHA: u64 = 5;
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 14;
+pub const N: usize = 16;
+pub const R: usize = 8;
+
+pub const EXTERNAL_CONSTANTS: &[[Mersenne31; 16]] = &[
+    [
+        Mersenne31(1988864850),
+        Mersenne31(1893772157),
+        Mersenne31(1025928330),
+        Mersenne31(1839472709),
+        Mersenne31(1611656994),
+        Mersenne31(1104858731),
+        Mersenne31(1694088660),
+        Mersenne31(1564660990),
+        Mersenne31(1991332205),
+        Mersenne31(1875486487),
+        Mersenne31(1890340790),
+        Mersenne31(1658614),
+        Mersenne31(582370530),
+        Mersenne31(528029397),
+        Mersenne31(1196956642),
+        Mersenne31(655401251),
+    ],
+    [
+        Mersenne31(1652877415),
+        Mersenne31(26032894),
+        Mersenne31(1576640243),
+        Mersenne31(1277052539),
+        Mersenne31(1450142396),
+        Mersenne31(697623591),
+        Mersenne31(1401580866),
+        Mersenne31(1568404175),
+        Mersenne31(2145004971),
+        Mersenne31(265835716),
+        Mersenne31(1183985610),
+        Mersenne31(1031234465),
+        Mersenne31(436012490),
+        Mersenne31(172735299),
+        Mersenne31(352802897),
+        Mersenne31(1032863094),
+    ],
+    [
+        Mersenne31(757665783),
+        Mersenne31(1082171296),
+        Mersenne31(1507509996),
+        Mersenne31(309929890),
+        Mersenne31(1807683232),
+        Mersenne31(43258895),
+        Mersenne31(611592566),
+        Mersenne31(1854193793),
+        Mersenne31(575164234),
+        Mersenne31(894217817),
+        Mersenne31(72613857),
+        Mersenne31(1061659596),
+        Mersenne31(8921166),
+        Mersenne31(1617355017),
+        Mersenne31(998001536),
+        Mersenne31(1800758877),
+    ],
+    [
+        Mersenne31(1002748055),
+        Mersenne31(1935405944),
+        Mersenne31(1351462722),
+        Mersenne31(411368491),
+        Mersenne31(1913975372),
+        Mersenne31(1956167178),
+        Mersenne31(442558016),
+        Mersenne31(855898408),
+        Mersenne31(699687798),
+        Mersenne31(1553382248),
+        Mersenne31(1708169125),
+        Mersenne31(490049183),
+        Mersenne31(1251643415),
+        Mersenne31(1193594742),
+        Mersenne31(880473871),
+        Mersenne31(511174042),
+    ],
+    [
+        Mersenne31(1545845660),
+        Mersenne31(1571179967),
+        Mersenne31(2049696221),
+        Mersenne31(489923800),
+        Mersenne31(1212825486),
+        Mersenne31(8024170),
+        Mersenne31(1472042855),
+        Mersenne31(1900622879),
+        Mersenne31(644345014),
+        Mersenne31(767104271),
+        Mersenne31(838877505),
+        Mersenne31(1170240143),
+        Mersenne31(1653817667),
+        Mersenne31(851549209),
+        Mersenne31(700266381),
+        Mersenne31(1530744878),
+    ],
+    [
+        Mersenne31(1657504853),
+        Mersenne31(722763601),
+        Mersenne31(1646069035),
+        Mersenne31(297431331),
+        Mersenne31(1505449150),
+        Mersenne31(1631923190),
+        Mersenne31(817545018),
+        Mersenne31(559650807),
+        Mersenne31(124784384),
+        Mersenne31(232392625),
+        Mersenne31(718894869),
+        Mersenne31(746078253),
+        Mersenne31(1620445342),
+        Mersenne31(610658159),
+        Mersenne31(1531514282),
+        Mersenne31(668349597),
+    ],
+    [
+        Mersenne31(1318947977),
+        Mersenne31(2067179133),
+        Mersenne31(1101166452),
+        Mersenne31(1900358186),
+        Mersenne31(1294966157),
+        Mersenne31(1545180440),
+        Mersenne31(1275166252),
+        Mersenne31(877615027),
+        Mersenne31(1890570669),
+        Mersenne31(1363262031),
+        Mersenne31(803235412),
+        Mersenne31(1952290984),
+        Mersenne31(51483180),
+        Mersenne31(861811719),
+        Mersenne31(1543398233),
+        Mersenne31(1962620596),
+    ],
+    [
+        Mersenne31(1101129880),
+        Mersenne31(1595397484),
+        Mersenne31(819053730),
+        Mersenne31(864084411),
+        Mersenne31(1094415504),
+        Mersenne31(1370326502),
+        Mersenne31(446128291),
+        Mersenne31(892553082),
+        Mersenne31(1121564363),
+        Mersenne31(675977724),
+        Mersenne31(82524664),
+        Mersenne31(791360430),
+        Mersenne31(1528948402),
+        Mersenne31(1253654946),
+        Mersenne31(385862305),
+        Mersenne31(1057147875),
+    ],
+];
+
+pub const INTERNAL_CONSTANTS: &[Mersenne31] = &[
+    Mersenne31(2139014335),
+    Mersenne31(398192464),
+    Mersenne31(1721194338),
+    Mersenne31(309794713),
+    Mersenne31(293113979),
+    Mersenne31(333470311),
+    Mersenne31(840890734),
+    Mersenne31(1751120490),
+    Mersenne31(346255565),
+    Mersenne31(917016030),
+    Mersenne31(1283969163),
+    Mersenne31(2023454402),
+    Mersenne31(17178960),
+    Mersenne31(670482084),
+];
+
+pub const INTERNAL_DIAGONAL: [Mersenne31; 16] = [
+    Mersenne31(1655072216),
+    Mersenne31(700505616),
+    Mersenne31(314227562),
+    Mersenne31(798358353),
+    Mersenne31(1784768890),
+    Mersenne31(1732342041),
+    Mersenne31(260871802),
+    Mersenne31(1640467205),
+    Mersenne31(1307652174),
+    Mersenne31(2143241297),
+    Mersenne31(177894229),
+    Mersenne31(964574542),
+    Mersenne31(847532359),
+    Mersenne31(960726403),
+    Mersenne31(1166850961),
+    Mersenne31(637331498),
+];
@@ -0,0 +1,178 @@
+//! The [Poseidon2](https://eprint.iacr.org/2023/323) permutation over small prime fields.
+//!
+//! After an initial application of the external matrix, the permutation applies
+//! half of the full rounds, all the partial rounds, and the remaining full rounds.
+//! Full rounds add round constants to, and apply the S-box on, every state element;
+//! partial rounds do so only for the first state element.
+use super::SmallField;
+use crate::hash::sponge::{algebraic_security_level, Sponge};
+
+#[derive(Clone)]
+pub struct Poseidon2State<F: SmallField, const R: usize, const N: usize> {
+    /// Number of full rounds.
+    pub full_rounds: usize,
+    /// Number of partial rounds.
+    pub partial_rounds: usize,
+    /// Exponent used in the S-box.
+    pub alpha: u64,
+    /// Round constants for the full rounds.
+    /// They are indexed by `external_constants[round_num][state_element_index]`
+    pub external_constants: &'static [[F; N]],
+    /// Round constants for the partial rounds.
+    pub internal_constants: &'static [F],
+    /// The diagonal $d$ of the internal matrix $1 1^T + \operatorname{diag}(d)$.
+    pub internal_diagonal: [F; N],
+
+    /// Sponge state
+    pub state: [F; N],
+}
+
+pub type Poseidon2Hash<F, const R: usize, const N: usize> =
+    crate::hash::sponge::DuplexSponge<Poseidon2State<F, R, N>>;
+
+impl<F: SmallField, const R: usize, const N: usize> AsRef<[F]> for Poseidon2State<F, R, N> {
+    fn as_ref(&self) -> &[F] {
+        &self.state
+    }
+}
+
+impl<F: SmallField, const R: usize, const N: usize> AsMut<[F]> for Poseidon2State<F, R, N> {
+    fn as_mut(&mut self) -> &mut [F] {
+        &mut self.state
+    }
+}
+
+impl<F: SmallField, const R: usize, const N: usize> Poseidon2State<F, R, N> {
+    /// Multiply the state by the block matrix `circ(2 M4, M4, ..., M4)`.
+    fn apply_external_matrix(state: &mut [F; N]) {
+        for chunk in state.chunks_exact_mut(4) {
+            apply_m4(chunk);
+        }
+        let mut sums = [F::ZERO; 4];
+        for chunk in state.chunks_exact(4) {
+            sums.iter_mut().zip(chunk).for_each(|(s, x)| *s += *x);
+        }
+        for chunk in state.chunks_exact_mut(4) {
+            chunk.iter_mut().zip(&sums).for_each(|(x, s)| *x += *s);
+        }
+    }
+
+    /// Multiply the state by the matrix $1 1^T + \operatorname{diag}(d)$.
+    fn apply_internal_matrix(&self, state: &mut [F; N]) {
+        let sum = state.iter().fold(F::ZERO, |acc, x| acc + *x);
+        for (x, d) in state.iter_mut().zip(&self.internal_diagonal) {
+            *x = *x * *d + sum;
+        }
+    }
+
+    fn full_round(&self, state: &mut [F; N], constants: &[F; N]) {
+        for (x, c) in state.iter_mut().zip(constants) {
+            *x = (*x + *c).pow(self.alpha);
+        }
+        Self::apply_external_matrix(state);
+    }
+
+    fn partial_round(&self, state: &mut [F; N], constant: F) {
+        state[0] = (state[0] + constant).pow(self.alpha);
+        self.apply_internal_matrix(state);
+    }
+}
+
+/// Multiply a chunk of four elements by the matrix
+/// `[[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]`.
+fn apply_m4<F: SmallField>(x: &mut [F]) {
+    let double = |x: F| x + x;
+    let t0 = x[0] + x[1];
+    let t1 = x[2] + x[3];
+    let t2 = double(x[1]) + t1;
+    let t3 = double(x[3]) + t0;
+    let t4 = double(double(t1)) + t3;
+    let t5 = double(double(t0)) + t2;
+    let t6 = t3 + t5;
+    let t7 = t2 + t4;
+    x.copy_from_slice(&[t6, t5, t7, t4]);
+}
+
+impl<F: SmallField, const R: usize, const N: usize> zeroize::Zeroize for Poseidon2State<F, R, N> {
+    fn zeroize(&mut self) {
+        self.state.zeroize();
+    }
+}
+
+impl<F, const R: usize, const N: usize> Sponge for Poseidon2State<F, R, N>
+where
+    Poseidon2State<F, R, N>: Default,
+    F: SmallField,
+{
+    type U = F;
+    const N: usize = N;
+    const R: usize = R;
+    const SECURITY_LEVEL: usize = algebraic_security_level(N - R, F::MODULUS_BIT_SIZE);
+
+    fn new(iv: [u8; 32]) -> Self {
+        assert!(
+            N >= 4 && N.is_multiple_of(4),
+            "Poseidon2 is defined here for widths that are a multiple of 4."
+        );
+        let mut sponge = Self::default();
+        assert_eq!(
+            sponge.full_rounds,
+            sponge.external_constants.len(),
+            "There must be one set of external constants per full round."
+        );
+        assert_eq!(
+            sponge.partial_rounds,
+            sponge.internal_constants.len(),
+            "There must be one internal constant per partial round."
+        );
+        super::write_iv(&mut sponge.state[R..], iv);
+        sponge
+    }
+
+    fn permute(&mut self) {
+        let half_full_rounds = self.full_rounds / 2;
+        let mut state = self.state;
+        Self::apply_external_matrix(&mut state);
+        for constants in &self.external_constants[..half_full_rounds] {
+            self.full_round(&mut state, constants);
+        }
+        for &constant in self.internal_constants {
+            self.partial_round(&mut state, constant);
+        }
+        for constants in &self.external_constants[half_full_rounds..] {
+            self.full_round(&mut state, constants);
+        }
+        self.state = state;
+    }
+}
+
+/// Initialization of constants.
+macro_rules! poseidon2_sponge {
+    ($name: ident, $path: tt) => {
+        pub type $name = crate::hash::sponge::DuplexSponge<
+            Poseidon2State<$path::Field, { $path::R }, { $path::N }>,
+        >;
+
+        impl Default for Poseidon2State<$path::Field, { $path::R }, { $path::N }> {
+            fn default() -> Self {
+                Self {
+                    full_rounds: $path::FULL_ROUNDS,
+                    partial_rounds: $path::PARTIAL_ROUNDS,
+                    alpha: $path::ALPHA,
+                    external_constants: $path::EXTERNAL_CONSTANTS,
+                    internal_constants: $path::INTERNAL_CONSTANTS,
+                    internal_diagonal: $path::INTERNAL_DIAGONAL,
+                    state: [<$path::Field as SmallField>::ZERO; $path::N],
+                }
+            }
+        }
+    };
+}
+
+mod babybear_16;
+mod goldilocks_12;
+mod mersenne31_16;
+
+poseidon2_sponge!(Poseidon2Goldilocks, goldilocks_12);
+poseidon2_sponge!(Poseidon2BabyBear, babybear_16);
+poseidon2_sponge!(Poseidon2Mersenne31, mersenne31_16);
@@ -0,0 +1,30 @@
+use super::{FieldReader, SmallField};
+use crate::traits::*;
+use crate::{Arthur, DuplexHash, ProofError, ProofResult};
+
+impl<'a, F, H> FieldReader<F> for Arthur<'a, H>
+where
+    F: SmallField,
+    H: DuplexHash,
+{
+    fn fill_next_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        let mut buf = vec![0u8; F::BYTES];
+        for o in output.iter_mut() {
+            self.fill_next_bytes(&mut buf)?;
+            F::read(&mut buf.as_slice(), core::slice::from_mut(o))
+                .map_err(|_| ProofError::SerializationError)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, F, H> FieldReader<F> for Arthur<'a, H, F>
+where
+    F: SmallField,
+    H: DuplexHash<F>,
+{
+    fn fill_next_scalars(&mut self, output: &mut [F]) -> ProofResult<()> {
+        self.fill_next_units(output)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use rand::{rngs::OsRng, RngCore};
+
+use super::monolith::{MonolithField, MonolithGoldilocks, MonolithMersenne31};
+use super::poseidon2::{Poseidon2BabyBear, Poseidon2Goldilocks, Poseidon2Mersenne31};
+use super::*;
+use crate::hash::Keccak;
+
+fn random<F: SmallField>() -> F {
+    F::from_u64_reduced(OsRng.next_u64())
+}
+
+fn check_field<F: SmallField>() {
+    let minus_one = F::from_u64_reduced(F::ORDER - 1);
+    assert_eq!(minus_one + F::ONE, F::ZERO);
+    assert_eq!(-F::ONE, minus_one);
+    assert_eq!(minus_one * minus_one, F::ONE);
+    assert_eq!(F::from_canonical_u64(F::ORDER), None);
+
+    let x = random::<F>();
+    let y = random::<F>();
+    assert_eq!(x - y + y, x);
+    assert_eq!(x * x.inverse().unwrap(), F::ONE);
+    assert_eq!(F::ZERO.inverse(), None);
+}
+
+#[test]
+fn test_fields() {
+    check_field::<Goldilocks>();
+    check_field::<BabyBear>();
+    check_field::<Mersenne31>();
+}
+
+#[test]
+fn test_unit_rejects_non_canonical() {
+    let mut element = [Goldilocks::ZERO];
+    let encoding = Goldilocks::ORDER.to_le_bytes();
+    assert!(Goldilocks::read(&mut &encoding[..], &mut element).is_err());
+
+    let mut element = [Mersenne31::ZERO];
+    let encoding = (Mersenne31::ORDER as u32).to_le_bytes();
+    assert!(Mersenne31::read(&mut &encoding[..], &mut element).is_err());
+}
+
+fn check_extension<F: Extendable<D>, const D: usize>() {
+    let random_ext = || ExtensionField::<F, D>(core::array::from_fn(|_| random()));
+    let (a, b, c) = (random_ext(), random_ext(), random_ext());
+    assert_eq!((a * b) * c, a * (b * c));
+    assert_eq!(a * (b + c), a * b + a * c);
+    assert_eq!(a * ExtensionField::one(), a);
+
+    // X^D reduces according to the reduction rule.
+    let mut x = [F::ZERO; D];
+    x[1] = F::ONE;
+    let mut x_pow = [F::ZERO; D];
+    x_pow[D - 1] = F::ONE;
+    assert_eq!(
+        ExtensionField(x) * ExtensionField(x_pow),
+        ExtensionField(F::REDUCTION)
+    );
+}
+
+#[test]
+fn test_extensions() {
+    check_extension::<Goldilocks, 4>();
+    check_extension::<Goldilocks, 5>();
+    check_extension::<BabyBear, 4>();
+    check_extension::<BabyBear, 5>();
+    check_extension::<Mersenne31, 4>();
+    check_extension::<Mersenne31, 5>();
+}
+
+#[test]
+fn test_monolith_bars_are_canonical() {
+    let g = Goldilocks::from_u64_reduced(Goldilocks::ORDER - 1).bar();
+    assert!(Goldilocks::from_canonical_u64(g.as_canonical_u64()).is_some());
+    let m = Mersenne31::from_u64_reduced(Mersenne31::ORDER - 1).bar();
+    assert!(Mersenne31::from_canonical_u64(m.as_canonical_u64()).is_some());
+}
+
+fn check_transcript<H, U, F, const D: usize>()
+where
+    U: Unit,
+    H: DuplexHash<U>,
+    F: Extendable<D>,
+    IOPattern<H, U>: FieldIOPattern<F>,
+    Merlin<H, U>: FieldWriter<F> + FieldChallenges<F>,
+    for<'a> Arthur<'a, H, U>: FieldReader<F> + FieldChallenges<F>,
+{
+    let io = IOPattern::<H, U>::new("github.com/mmaker/nimue");
+    let io = FieldIOPattern::<F>::add_scalars(io, 20, "elements");
+    let io = ExtensionIOPattern::<F, D>::challenge_extension_scalars(io, 3, "challenges");
+    let io = FieldIOPattern::<F>::add_scalars(io, 1, "response");
+
+    let elements: [F; 20] = core::array::from_fn(|_| random());
+    let mut merlin = io.to_merlin();
+    merlin.add_scalars(&elements).unwrap();
+    let challenges: [ExtensionField<F, D>; 3] = merlin.challenge_extension_scalars().unwrap();
+    merlin.add_scalars(&challenges[0].0[..1]).unwrap();
+    assert_ne!(challenges[0], challenges[1]);
+
+    let mut arthur = io.to_arthur(merlin.transcript());
+    let elements_verifier: [F; 20] = arthur.next_scalars().unwrap();
+    assert_eq!(elements, elements_verifier);
+    let challenges_verifier: [ExtensionField<F, D>; 3] =
+        arthur.challenge_extension_scalars().unwrap();
+    assert_eq!(challenges, challenges_verifier);
+    let [response]: [F; 1] = arthur.next_scalars().unwrap();
+    assert_eq!(response, challenges[0].0[0]);
+}
+
+#[test]
+fn test_poseidon2_transcript() {
+    check_transcript::<Poseidon2Goldilocks, Goldilocks, Goldilocks, 4>();
+    check_transcript::<Poseidon2Goldilocks, Goldilocks, Goldilocks, 5>();
+    check_transcript::<Poseidon2BabyBear, BabyBear, BabyBear, 4>();
+    check_transcript::<Poseidon2BabyBear, BabyBear, BabyBear, 5>();
+    check_transcript::<Poseidon2Mersenne31, Mersenne31, Mersenne31, 4>();
+    check_transcript::<Poseidon2Mersenne31, Mersenne31, Mersenne31, 5>();
+}
+
+#[test]
+fn test_monolith_transcript() {
+    check_transcript::<MonolithGoldilocks, Goldilocks, Goldilocks, 4>();
+    check_transcript::<MonolithMersenne31, Mersenne31, Mersenne31, 5>();
+}
+
+#[test]
+fn test_keccak_transcript() {
+    check_transcript::<Keccak, u8, Goldilocks, 5>();
+    check_transcript::<Keccak, u8, BabyBear, 4>();
+    check_transcript::<Keccak, u8, Mersenne31, 4>();
+}
+
+#[test]
+fn test_iv_is_used() {
+    fn check<H: DuplexHash<F>, F: SmallField>() {
+        let mut output1 = [F::ZERO; 4];
+        let mut output2 = [F::ZERO; 4];
+        H::new([1u8; 32]).squeeze_unchecked(&mut output1);
+        H::new([2u8; 32]).squeeze_unchecked(&mut output2);
+        assert_ne!(output1, output2);
+    }
+    check::<Poseidon2Goldilocks, Goldilocks>();
+    check::<Poseidon2BabyBear, BabyBear>();
+    check::<Poseidon2Mersenne31, Mersenne31>();
+    check::<MonolithGoldilocks, Goldilocks>();
+    check::<MonolithMersenne31, Mersenne31>();
+}
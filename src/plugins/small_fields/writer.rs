@@ -0,0 +1,29 @@
+use rand::{CryptoRng, RngCore};
+
+use super::{FieldPublic, FieldWriter, SmallField};
+use crate::{DuplexHash, Merlin, ProofResult};
+
+impl<F, H, R> FieldWriter<F> for Merlin<H, u8, R>
+where
+    F: SmallField,
+    H: DuplexHash,
+    R: RngCore + CryptoRng,
+{
+    fn add_scalars(&mut self, input: &[F]) -> ProofResult<()> {
+        let serialized = self.public_scalars(input);
+        self.transcript.extend(serialized?);
+        Ok(())
+    }
+}
+
+impl<F, H, R> FieldWriter<F> for Merlin<H, F, R>
+where
+    F: SmallField,
+    H: DuplexHash<F>,
+    R: RngCore + CryptoRng,
+{
+    fn add_scalars(&mut self, input: &[F]) -> ProofResult<()> {
+        self.add_units(input)?;
+        Ok(())
+    }
+}
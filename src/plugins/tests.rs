@@ -1,4 +1,3 @@
-use ark_ec::CurveGroup;
 use ark_serialize::CanonicalSerialize;
 use group::GroupEncoding;
 
@@ -20,6 +19,8 @@ where
         .add_points(1, "com")
         .challenge_bytes(16, "chal")
         .challenge_scalars(1, "chal")
+        .challenge_short_scalars(2, 128, "short chal")
+        .challenge_bits(10, "bits")
 }
 
 fn ark_iopattern<G, H>() -> IOPattern<H>
@@ -36,6 +37,8 @@ where
         .add_points(1, "com")
         .challenge_bytes(16, "chal")
         .challenge_scalars(1, "chal")
+        .challenge_short_scalars(2, 128, "short chal")
+        .challenge_bits(10, "bits")
 }
 
 #[test]
@@ -67,9 +70,10 @@ fn test_compatible_pasta() {
 // Check that the transcripts generated using the Group trait can be compatible with transcripts generated using group.
 fn compatible_groups<ArkG, GroupG>()
 where
-    ArkG: CurveGroup,
-    GroupG: group::Group + GroupEncoding,
+    ArkG: plugins::ark::EncodableCurve,
+    GroupG: group::Group + GroupEncoding + Default,
     GroupG::Repr: AsRef<[u8]>,
+    for<'a> crate::Arthur<'a, Keccak>: plugins::group::FieldReader<GroupG::Scalar>,
 {
     use group::ff::PrimeField;
 
@@ -134,4 +138,302 @@ where
         .unwrap();
     let group_scalar_bytes = group_chal_scalar.to_repr();
     assert_eq!(&ark_scalar_bytes, group_scalar_bytes.as_ref());
+    // Check that short scalar challenges are the same, and short.
+    let ark_short: [ArkG::ScalarField; 2] =
+        plugins::ark::FieldChallenges::challenge_short_scalars(&mut ark_prover, 128).unwrap();
+    let group_short: [GroupG::Scalar; 2] =
+        plugins::group::FieldChallenges::challenge_short_scalars(&mut group_prover, 128).unwrap();
+    for (ark_short, group_short) in ark_short.iter().zip(&group_short) {
+        let mut ark_short_bytes = Vec::new();
+        ark_short
+            .serialize_compressed(&mut ark_short_bytes)
+            .unwrap();
+        assert_eq!(&ark_short_bytes, group_short.to_repr().as_ref());
+        // Little-endian encoding: the upper half is zero.
+        assert!(ark_short_bytes[16..].iter().all(|&b| b == 0));
+    }
+    assert_ne!(ark_short[0], ark_short[1]);
+    assert_eq!(
+        ark_prover.challenge_bits(10).unwrap(),
+        group_prover.challenge_bits(10).unwrap()
+    );
+
+    // Check that transcripts written with arkworks can be read with group, and vice versa.
+    let mut group_verifier = group_io.to_arthur(ark_prover.transcript());
+    let [scalar]: [GroupG::Scalar; 1] =
+        plugins::group::FieldReader::next_scalars(&mut group_verifier).unwrap();
+    assert_eq!(scalar, group_scalar);
+    group_verifier
+        .fill_challenge_bytes(&mut group_chal)
+        .unwrap();
+    // Groups with a cofactor cannot be read with `GroupReader`: compare the encodings instead.
+    let mut point_bytes = GroupG::Repr::default();
+    crate::ByteReader::fill_next_bytes(&mut group_verifier, point_bytes.as_mut()).unwrap();
+    assert_eq!(point_bytes.as_ref(), group_point.to_bytes().as_ref());
+
+    let mut ark_verifier = ark_io.to_arthur(group_prover.transcript());
+    let [scalar]: [ArkG::ScalarField; 1] =
+        plugins::ark::FieldReader::next_scalars(&mut ark_verifier).unwrap();
+    assert_eq!(scalar, ark_scalar);
+    ark_verifier.fill_challenge_bytes(&mut ark_chal).unwrap();
+    let [point]: [ArkG; 1] = plugins::ark::GroupReader::next_points(&mut ark_verifier).unwrap();
+    assert_eq!(point, ark_point);
+}
+
+#[test]
+fn test_group_reader_rejects_invalid_points() {
+    use crate::ByteWriter;
+    use curve25519_dalek::ristretto::RistrettoPoint;
+    use group::Group;
+    use plugins::group::{GroupIOPattern, GroupReader};
+
+    let io =
+        GroupIOPattern::<RistrettoPoint>::add_points(IOPattern::<Keccak>::new("points"), 1, "p");
+    let read_point = |bytes: [u8; 32]| {
+        let mut merlin = io.to_merlin();
+        merlin.add_bytes(&bytes).unwrap();
+        let mut arthur = io.to_arthur(merlin.transcript());
+        GroupReader::<RistrettoPoint>::next_points::<1>(&mut arthur)
+    };
+
+    let generator = RistrettoPoint::generator();
+    assert_eq!(read_point(generator.to_bytes()).unwrap(), [generator]);
+    // The identity is accepted, as in the arkworks plugin.
+    let identity = RistrettoPoint::identity();
+    assert_eq!(read_point(identity.to_bytes()).unwrap(), [identity]);
+    // Non-canonical encodings are rejected.
+    assert!(read_point([0xff; 32]).is_err());
+}
+
+/// A transcript that only implements [`FieldChallenges::fill_challenge_scalars`][`plugins::ark::FieldChallenges::fill_challenge_scalars`].
+struct FullScalarsOnly<T>(T);
+
+impl<T: ByteChallenges> plugins::ark::FieldChallenges<ark_bls12_381::Fr> for FullScalarsOnly<T> {
+    fn fill_challenge_scalars(
+        &mut self,
+        output: &mut [ark_bls12_381::Fr],
+    ) -> crate::ProofResult<()> {
+        plugins::ark::FieldChallenges::fill_challenge_scalars(&mut self.0, output)
+    }
+}
+
+impl<T: crate::UnitTranscript<u8>> plugins::group::FieldChallenges<bls12_381::Scalar>
+    for FullScalarsOnly<T>
+{
+    fn fill_challenge_scalars(
+        &mut self,
+        output: &mut [bls12_381::Scalar],
+    ) -> crate::ProofResult<()> {
+        plugins::group::FieldChallenges::fill_challenge_scalars(&mut self.0, output)
+    }
+}
+
+#[test]
+fn test_default_short_scalars() {
+    use ark_ff::{BigInteger, PrimeField};
+    use group::ff::PrimeField as _;
+
+    let io = IOPattern::<Keccak>::new("short scalars").challenge_bytes(64, "full");
+    let [full]: [ark_bls12_381::Fr; 1] =
+        plugins::ark::FieldChallenges::challenge_scalars(&mut io.to_merlin()).unwrap();
+    let full_bytes = full.into_bigint().to_bytes_le();
+
+    // By default, short scalars are the least significant bits of full scalars.
+    let mut ark_transcript = FullScalarsOnly(io.to_merlin());
+    let [ark_short]: [ark_bls12_381::Fr; 1] =
+        plugins::ark::FieldChallenges::challenge_short_scalars(&mut ark_transcript, 100).unwrap();
+    let mut group_transcript = FullScalarsOnly(io.to_merlin());
+    let [group_short]: [bls12_381::Scalar; 1] =
+        plugins::group::FieldChallenges::challenge_short_scalars(&mut group_transcript, 100)
+            .unwrap();
+
+    let mut expected = full_bytes[..13].to_vec();
+    expected[12] &= 0x0f;
+    expected.resize(32, 0);
+    assert_eq!(ark_short.into_bigint().to_bytes_le(), expected);
+    assert_eq!(group_short.to_repr().as_ref(), &expected[..]);
+
+    // Short scalars must be shorter than the modulus.
+    let mut transcript = FullScalarsOnly(io.to_merlin());
+    assert!(
+        plugins::ark::FieldChallenges::<ark_bls12_381::Fr>::challenge_short_scalars::<1>(
+            &mut transcript,
+            255
+        )
+        .is_err()
+    );
+}
+
+/// Transcripts over the base field of Pallas, with the `group` plugin.
+mod group_algebraic {
+    use group::ff::{Field, PrimeField};
+    use group::{Group, GroupEncoding};
+    use pasta_curves::{pallas, Fp};
+
+    use crate::hash::sponge::{DuplexSponge, Sponge};
+    use crate::plugins::group::{
+        FieldChallenges, FieldIOPattern, FieldReader, FieldUnit, FieldWriter, GroupIOPattern,
+        GroupReader, GroupWriter,
+    };
+    use crate::{ByteChallenges, ByteIOPattern, IOPattern, UnitTranscript};
+
+    /// A toy sponge of width 3 over [`Fp`], with an $x^5$ S-box. **Not** secure.
+    #[derive(Clone, Default)]
+    struct ToyState([FieldUnit<Fp>; 3]);
+
+    impl zeroize::Zeroize for ToyState {
+        fn zeroize(&mut self) {
+            self.0.iter_mut().for_each(zeroize::Zeroize::zeroize);
+        }
+    }
+
+    impl Sponge for ToyState {
+        type U = FieldUnit<Fp>;
+        const N: usize = 3;
+        const R: usize = 2;
+        const SECURITY_LEVEL: usize = 127;
+
+        fn new(iv: [u8; 32]) -> Self {
+            let mut state = Self::default();
+            state.0[2].0 = iv
+                .iter()
+                .fold(Fp::ZERO, |acc, &b| acc * Fp::from(256) + Fp::from(b as u64));
+            state
+        }
+
+        fn permute(&mut self) {
+            for round in 0..8u64 {
+                for (i, x) in self.0.iter_mut().enumerate() {
+                    let y = x.0 + Fp::from(3 * round + i as u64 + 1);
+                    x.0 = y.square().square() * y;
+                }
+                let sum = self.0.iter().fold(Fp::ZERO, |acc, x| acc + x.0);
+                self.0.iter_mut().for_each(|x| x.0 += sum);
+            }
+        }
+    }
+
+    impl AsRef<[FieldUnit<Fp>]> for ToyState {
+        fn as_ref(&self) -> &[FieldUnit<Fp>] {
+            &self.0
+        }
+    }
+
+    impl AsMut<[FieldUnit<Fp>]> for ToyState {
+        fn as_mut(&mut self) -> &mut [FieldUnit<Fp>] {
+            &mut self.0
+        }
+    }
+
+    type ToyHash = DuplexSponge<ToyState>;
+
+    #[test]
+    fn test_group_algebraic_transcript() {
+        let io = IOPattern::<ToyHash, FieldUnit<Fp>>::new("algebraic");
+        let io = FieldIOPattern::<Fp>::add_scalars(io, 2, "scalars");
+        let io = GroupIOPattern::<pallas::Point>::add_points(io, 1, "point");
+        let io = FieldIOPattern::<Fp>::challenge_scalars(io, 1, "chal");
+        let io = io.challenge_bytes(16, "bytes");
+        let io = FieldIOPattern::<Fp>::challenge_short_scalars(io, 1, 64, "short");
+        // Points are 32 bytes, absorbed as two field elements.
+        // 16 challenge bytes take (128 + 128) / 254 = 2 field elements.
+        assert_eq!(
+            io.as_bytes(),
+            b"algebraic\0A2scalars\0A2point\0S1chal\0S2bytes\0S1short"
+        );
+
+        let scalars = [Fp::from(1), Fp::from(2)];
+        let point = pallas::Point::generator() * pallas::Scalar::from(0x42);
+        let mut merlin = io.to_merlin();
+        merlin.add_scalars(&scalars).unwrap();
+        merlin.add_points(&[point]).unwrap();
+        let [chal]: [Fp; 1] = merlin.challenge_scalars().unwrap();
+        let bytes = merlin.challenge_bytes::<16>().unwrap();
+        let [short]: [Fp; 1] = merlin.challenge_short_scalars(64).unwrap();
+        assert_ne!(chal, Fp::ZERO);
+        assert!(short.to_repr()[8..].iter().all(|&b| b == 0));
+        let transcript = merlin.transcript().to_vec();
+        assert_eq!(transcript.len(), 3 * 32);
+
+        let mut arthur = io.to_arthur(&transcript);
+        assert_eq!(arthur.next_scalars::<2>().unwrap(), scalars);
+        let [point_read]: [pallas::Point; 1] = arthur.next_points().unwrap();
+        assert_eq!(point_read, point);
+        let [chal_read]: [Fp; 1] = arthur.challenge_scalars().unwrap();
+        assert_eq!(chal_read, chal);
+        assert_eq!(arthur.challenge_bytes::<16>().unwrap(), bytes);
+        let [short_read]: [Fp; 1] = arthur.challenge_short_scalars(64).unwrap();
+        assert_eq!(short_read, short);
+
+        // Challenge bytes are the least significant bytes of x_0 + x_1 p.
+        let bytes_io =
+            IOPattern::<ToyHash, FieldUnit<Fp>>::new("bytes").challenge_bytes(16, "bytes");
+        let bytes = bytes_io.to_arthur(&[]).challenge_bytes::<16>().unwrap();
+        let mut units = [FieldUnit(Fp::ZERO); 2];
+        bytes_io
+            .to_arthur(&[])
+            .fill_challenge_units(&mut units)
+            .unwrap();
+        let [x0, x1] = units.map(|x| u128::from_le_bytes(x.0.to_repr()[..16].try_into().unwrap()));
+        let p = 0x224698fc094cf91b992d30ed00000001u128;
+        assert_eq!(bytes, x1.wrapping_mul(p).wrapping_add(x0).to_le_bytes());
+
+        // The identity is accepted, and invalid encodings are rejected.
+        let mut tampered = transcript.clone();
+        tampered[64..].copy_from_slice(pallas::Point::identity().to_bytes().as_ref());
+        let mut arthur = io.to_arthur(&tampered);
+        arthur.next_scalars::<2>().unwrap();
+        let [identity]: [pallas::Point; 1] = arthur.next_points().unwrap();
+        assert_eq!(identity, pallas::Point::identity());
+        tampered[64..].fill(0xff);
+        let mut arthur = io.to_arthur(&tampered);
+        arthur.next_scalars::<2>().unwrap();
+        assert!(GroupReader::<pallas::Point>::next_points::<1>(&mut arthur).is_err());
+    }
+
+    /// The Pasta Poseidon parameters are the ones of Orchard.
+    #[test]
+    #[cfg(feature = "pasta_curves")]
+    fn test_pasta_poseidon_parameters() {
+        use crate::plugins::group::poseidon::{pasta, PoseidonSponge};
+
+        fn from_hex<F: PrimeField<Repr = [u8; 32]>>(s: &str) -> F {
+            let mut repr: [u8; 32] = hex::decode(s).unwrap().try_into().unwrap();
+            repr.reverse();
+            F::from_repr(repr).unwrap()
+        }
+
+        let pallas = PoseidonSponge::<Fp, 2, 3>::default();
+        assert_eq!((pallas.full_rounds, pallas.partial_rounds), (8, 56));
+        assert_eq!(pallas.ark.len(), 64);
+        assert_eq!(
+            pallas.ark[0][0],
+            from_hex("360d7470611e473d353f628f76d110f34e71162f31003b7057538c2596426303")
+        );
+        assert_eq!(
+            pallas.mds[0][0],
+            from_hex("0ab5e5b874a68de7b3d59fbdc8c9ead497d7a0ab23850b56323f2486d7e11b63")
+        );
+
+        let vesta = PoseidonSponge::<pasta_curves::Fq, 2, 3>::default();
+        assert_eq!(
+            vesta.ark[0][0],
+            from_hex("360d7470611e473d353f628f76d110f34e71162f31003b7057538c2596426303")
+        );
+        assert_eq!(
+            vesta.mds[0][0],
+            from_hex("1853b4977c6fa22791913f56cf21af2b5f710afc43ddc5f6eb4f1f742963421f")
+        );
+
+        // The sponges can be used in transcripts over the Pasta fields.
+        let io = IOPattern::<pasta::PoseidonPallas, FieldUnit<Fp>>::new("pasta");
+        let io = FieldIOPattern::<Fp>::add_scalars(io, 1, "scalar");
+        let io = FieldIOPattern::<Fp>::challenge_scalars(io, 1, "chal");
+        let mut merlin = io.to_merlin();
+        merlin.add_scalars(&[Fp::from(42)]).unwrap();
+        let [chal]: [Fp; 1] = merlin.challenge_scalars().unwrap();
+        let mut arthur = io.to_arthur(merlin.transcript());
+        assert_eq!(arthur.next_scalars::<1>().unwrap(), [Fp::from(42)]);
+        assert_eq!(arthur.challenge_scalars::<1>().unwrap(), [chal]);
+    }
 }
@@ -1,9 +1,19 @@
 macro_rules! field_traits {
-    ($Field:path) => {
+    ($Field:path, short_scalars: $fill_short:path $(, where F: $Short:path)?) => {
         /// Absorb and squeeze field elements to the IO pattern.
         pub trait FieldIOPattern<F: $Field> {
             fn add_scalars(self, count: usize, label: &str) -> Self;
             fn challenge_scalars(self, count: usize, label: &str) -> Self;
+
+            /// Squeeze `count` scalars of `bits` bits, cf. [`FieldChallenges::fill_challenge_short_scalars`].
+            ///
+            /// By default, one full scalar is squeezed per short scalar.
+            fn challenge_short_scalars(self, count: usize, _bits: usize, label: &str) -> Self
+            where
+                Self: Sized,
+            {
+                self.challenge_scalars(count, label)
+            }
         }
 
         /// Interpret verifier messages as uniformly distributed field elements.
@@ -13,21 +23,49 @@ macro_rules! field_traits {
         pub trait FieldChallenges<F: $Field> {
             fn fill_challenge_scalars(&mut self, output: &mut [F]) -> $crate::ProofResult<()>;
 
-            fn challenge_scalars<const N: usize>(&mut self) -> crate::ProofResult<[F; N]> {
+            fn challenge_scalars<const N: usize>(&mut self) -> $crate::ProofResult<[F; N]> {
                 let mut output = [F::default(); N];
                 self.fill_challenge_scalars(&mut output).map(|()| output)
             }
+
+            /// Squeeze scalars uniformly distributed in $[0, 2^{\text{bits}})$,
+            /// e.g. 128-bit challenges for faster (endomorphism-friendly) scalar multiplication.
+            ///
+            /// `bits` must be smaller than the bit size of the modulus.
+            ///
+            /// By default, full scalars are squeezed and reduced to their `bits` least significant bits,
+            /// which is $2^{\text{bits}} / p$-close to uniform.
+            fn fill_challenge_short_scalars(
+                &mut self,
+                output: &mut [F],
+                bits: usize,
+            ) -> $crate::ProofResult<()>
+            $(where F: $Short)?
+            {
+                $fill_short(self, output, bits)
+            }
+
+            fn challenge_short_scalars<const N: usize>(
+                &mut self,
+                bits: usize,
+            ) -> $crate::ProofResult<[F; N]>
+            $(where F: $Short)?
+            {
+                let mut output = [F::default(); N];
+                self.fill_challenge_short_scalars(&mut output, bits)
+                    .map(|()| output)
+            }
         }
 
         /// Add field elements as shared public information.
         pub trait FieldPublic<F: $Field> {
             type Repr;
-            fn public_scalars(&mut self, input: &[F]) -> crate::ProofResult<Self::Repr>;
+            fn public_scalars(&mut self, input: &[F]) -> $crate::ProofResult<Self::Repr>;
         }
 
         /// Add field elements to the protocol transcript.
         pub trait FieldWriter<F: $Field>: FieldPublic<F> {
-            fn add_scalars(&mut self, input: &[F]) -> crate::ProofResult<()>;
+            fn add_scalars(&mut self, input: &[F]) -> $crate::ProofResult<()>;
         }
 
         /// Retrieve field elements from the protocol trainscript.
@@ -35,9 +73,9 @@ macro_rules! field_traits {
         /// The implementation of this trait **MUST** ensure that the field elements
         /// are correct encodings.
         pub trait FieldReader<F: $Field>: FieldPublic<F> {
-            fn fill_next_scalars(&mut self, output: &mut [F]) -> crate::ProofResult<()>;
+            fn fill_next_scalars(&mut self, output: &mut [F]) -> $crate::ProofResult<()>;
 
-            fn next_scalars<const N: usize>(&mut self) -> crate::ProofResult<[F; N]> {
+            fn next_scalars<const N: usize>(&mut self) -> $crate::ProofResult<[F; N]> {
                 let mut output = [F::default(); N];
                 self.fill_next_scalars(&mut output).map(|()| output)
             }
@@ -86,5 +124,6 @@ macro_rules! group_traits {
     };
 }
 
+pub(super) use field_traits;
 #[cfg(any(feature = "group", feature = "ark"))]
-pub(super) use {field_traits, group_traits};
+pub(super) use group_traits;
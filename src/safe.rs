@@ -2,10 +2,13 @@ use core::fmt;
 use core::marker::PhantomData;
 use std::collections::vec_deque::VecDeque;
 
+use zeroize::Zeroizing;
+
+use super::checkpoint::{StateKind, StateReader, StateWriter};
 use super::errors::IOPatternError;
 use super::hash::Unit;
 use super::hash::{DuplexHash, Keccak};
-use super::iopattern::{IOPattern, Op};
+use super::iopattern::{IOPattern, Op, PointEncoding};
 
 /// A (slightly modified) SAFE API for sponge functions.
 ///
@@ -18,6 +21,7 @@ where
 {
     sponge: H,
     stack: VecDeque<Op>,
+    point_encoding: PointEncoding,
     _unit: PhantomData<U>,
 }
 
@@ -27,7 +31,17 @@ impl<U: Unit, H: DuplexHash<U>> Safe<H, U> {
     pub fn new(io_pattern: &IOPattern<H, U>) -> Self {
         let stack = io_pattern.finalize();
         let tag = Self::generate_tag(io_pattern.as_bytes());
-        Self::unchecked_load_with_stack(tag, stack)
+        Self {
+            sponge: H::with_io_pattern(io_pattern.as_bytes(), tag),
+            stack,
+            point_encoding: io_pattern.point_encoding(),
+            _unit: PhantomData,
+        }
+    }
+
+    /// The encoding of group elements set in the IO Pattern, cf. [`IOPattern::with_point_encoding`].
+    pub fn point_encoding(&self) -> PointEncoding {
+        self.point_encoding
     }
 
     /// Finish the block and compress the state.
@@ -114,21 +128,56 @@ impl<U: Unit, H: DuplexHash<U>> Safe<H, U> {
         }
     }
 
-    fn generate_tag(iop_bytes: &[u8]) -> [u8; 32] {
+    /// Export the state of the sponge, together with the operations left to perform.
+    ///
+    /// The output can be loaded back with [`Safe::import_state`],
+    /// for instance to resume the protocol on another machine.
+    /// It contains secret information and is zeroized on drop.
+    pub fn export_state(&self) -> Result<Zeroizing<Vec<u8>>, IOPatternError> {
+        let sponge = self.sponge.export_state()?;
+        let len = 9 + 9 * self.stack.len() + sponge.len();
+        let mut writer = StateWriter::new(StateKind::Safe, len);
+        writer
+            .write_bytes(&[self.point_encoding.id()])
+            .write_u64(self.stack.len() as u64);
+        for op in &self.stack {
+            let (id, length) = match op {
+                Op::Absorb(length) => (b'A', *length),
+                Op::Squeeze(length) => (b'S', *length),
+                Op::Ratchet => (b'R', 0),
+            };
+            writer.write_bytes(&[id]).write_u64(length as u64);
+        }
+        writer.write_bytes(&sponge);
+        Ok(writer.finish())
+    }
+
+    /// Load a state exported with [`Safe::export_state`].
+    pub fn import_state(state: &[u8]) -> Result<Self, IOPatternError> {
+        let mut reader = StateReader::new(state, StateKind::Safe)?;
+        let point_encoding = PointEncoding::from_id(reader.read_bytes(1)?[0])?;
+        let ops = reader.read_usize()?;
+        let mut stack = VecDeque::new();
+        for _ in 0..ops {
+            let id = reader.read_bytes(1)?[0] as char;
+            stack.push_back(Op::new(id, Some(reader.read_usize()?))?);
+        }
+        let sponge = H::import_state(reader.read_rest())?;
+        Ok(Self {
+            sponge,
+            stack,
+            point_encoding,
+            _unit: PhantomData,
+        })
+    }
+
+    pub(crate) fn generate_tag(iop_bytes: &[u8]) -> [u8; 32] {
         let mut keccak = Keccak::default();
         keccak.absorb_unchecked(iop_bytes);
         let mut tag = [0u8; 32];
         keccak.squeeze_unchecked(&mut tag);
         tag
     }
-
-    fn unchecked_load_with_stack(tag: [u8; 32], stack: VecDeque<Op>) -> Self {
-        Self {
-            sponge: H::new(tag),
-            stack,
-            _unit: PhantomData,
-        }
-    }
 }
 
 impl<U: Unit, H: DuplexHash<U>> Drop for Safe<H, U> {
@@ -1,14 +1,22 @@
 use rand::RngCore;
 
-use crate::hash::keccak::Keccak;
+use crate::hash::ascon::Ascon;
+use crate::hash::keccak::{Keccak, Keccak512};
 use crate::hash::legacy::DigestBridge;
+use crate::hash::turboshake::{TurboShake128, TurboShake256};
+use crate::hash::xof::XofBridge;
+use crate::hash::xoodoo::Xoodoo;
 use crate::{
-    ByteChallenges, BytePublic, ByteReader, ByteWriter, DuplexHash, IOPattern, Merlin, Safe,
+    ByteChallenges, ByteIOPattern, BytePublic, ByteReader, ByteWriter, DefaultHash, DefaultRng,
+    DuplexHash, IOPattern, Merlin, Safe,
 };
 
 type Sha2 = DigestBridge<sha2::Sha256>;
 type Blake2b512 = DigestBridge<blake2::Blake2b512>;
 type Blake2s256 = DigestBridge<blake2::Blake2s256>;
+type Shake128 = XofBridge<sha3::Shake128>;
+type Shake256 = XofBridge<sha3::Shake256>;
+type Blake3 = XofBridge<blake3::Hasher>;
 
 /// How should a protocol without actual IO be handled?
 #[test]
@@ -210,4 +218,177 @@ fn test_streaming_blake2() {
 #[test]
 fn test_streaming_keccak() {
     test_streaming_absorb_and_squeeze::<Keccak>();
+    test_streaming_absorb_and_squeeze::<Keccak512>();
+}
+
+#[test]
+fn test_streaming_turboshake() {
+    test_streaming_absorb_and_squeeze::<TurboShake128>();
+    test_streaming_absorb_and_squeeze::<TurboShake256>();
+}
+
+#[test]
+fn test_streaming_ascon() {
+    test_streaming_absorb_and_squeeze::<Ascon>();
+}
+
+#[test]
+fn test_streaming_xoodoo() {
+    test_streaming_absorb_and_squeeze::<Xoodoo>();
+}
+
+#[test]
+fn test_streaming_shake() {
+    test_streaming_absorb_and_squeeze::<Shake128>();
+    test_streaming_absorb_and_squeeze::<Shake256>();
+}
+
+#[test]
+fn test_streaming_blake3() {
+    test_streaming_absorb_and_squeeze::<Blake3>();
+}
+
+#[test]
+fn test_security_level() {
+    assert_eq!(Keccak::SECURITY_LEVEL, 256);
+    assert_eq!(Keccak512::SECURITY_LEVEL, 512);
+    assert_eq!(TurboShake128::SECURITY_LEVEL, 128);
+    assert_eq!(TurboShake256::SECURITY_LEVEL, 256);
+    assert_eq!(Ascon::SECURITY_LEVEL, 128);
+    assert_eq!(Xoodoo::SECURITY_LEVEL, 128);
+    assert_eq!(Sha2::SECURITY_LEVEL, 128);
+    assert_eq!(Blake2b512::SECURITY_LEVEL, 256);
+    assert_eq!(Shake128::SECURITY_LEVEL, 128);
+}
+
+/// Exporting and importing the state of a hash function should not affect its output.
+fn check_export_import<H: DuplexHash>() {
+    let mut hash = H::new([0x42; 32]);
+    hash.absorb_unchecked(b"yellow submarine");
+    let mut first = [0u8; 10];
+    hash.squeeze_unchecked(&mut first);
+
+    let mut resumed = H::import_state(&hash.export_state().unwrap()).unwrap();
+    let mut expected = [0u8; 100];
+    let mut got = [0u8; 100];
+    hash.squeeze_unchecked(&mut expected)
+        .absorb_unchecked(b"beatles")
+        .squeeze_unchecked(&mut expected);
+    resumed
+        .squeeze_unchecked(&mut got)
+        .absorb_unchecked(b"beatles")
+        .squeeze_unchecked(&mut got);
+    assert_eq!(expected, got);
+
+    // Also in the middle of an absorption.
+    hash.absorb_unchecked(b"yellow");
+    let mut resumed = H::import_state(&hash.export_state().unwrap()).unwrap();
+    hash.absorb_unchecked(b" submarine")
+        .squeeze_unchecked(&mut expected);
+    resumed
+        .absorb_unchecked(b" submarine")
+        .squeeze_unchecked(&mut got);
+    assert_eq!(expected, got);
+}
+
+#[test]
+fn test_export_import_hash() {
+    check_export_import::<Keccak>();
+    check_export_import::<TurboShake128>();
+    check_export_import::<Ascon>();
+    check_export_import::<Sha2>();
+    check_export_import::<Blake2b512>();
+
+    // States cannot be mixed up.
+    let keccak_state = Keccak::new([0; 32]).export_state().unwrap();
+    assert!(Sha2::import_state(&keccak_state).is_err());
+    assert!(TurboShake128::import_state(&keccak_state[..50]).is_err());
+    // Keccak and Keccak512 have the same width but different rates.
+    let mut keccak = Keccak::new([0; 32]);
+    keccak.squeeze_unchecked(&mut [0u8; 10]);
+    let err = Keccak512::import_state(&keccak.export_state().unwrap())
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("rate 72"));
+    let mut unknown_version = keccak_state.clone();
+    unknown_version[0] = 0xff;
+    assert!(Keccak::import_state(&unknown_version).is_err());
+}
+
+#[test]
+fn test_merlin_checkpoint() {
+    let io = IOPattern::<DefaultHash>::new("checkpoint")
+        .absorb(16, "first message")
+        .squeeze(16, "first challenge")
+        .absorb(32, "second message")
+        .squeeze(16, "second challenge");
+
+    let mut merlin = io.to_merlin();
+    merlin.add_bytes(&[1u8; 16]).unwrap();
+    let mut challenge = [0u8; 16];
+    merlin.fill_challenge_bytes(&mut challenge[..4]).unwrap();
+
+    let state = merlin.export_state().unwrap();
+    let mut resumed = Merlin::<DefaultHash>::import_state(&state, DefaultRng::default()).unwrap();
+    for merlin in [&mut merlin, &mut resumed] {
+        merlin.fill_challenge_bytes(&mut challenge[4..]).unwrap();
+        merlin.add_bytes(&[2u8; 32]).unwrap();
+        merlin.fill_challenge_bytes(&mut [0u8; 16]).unwrap();
+    }
+    assert_eq!(merlin.transcript(), resumed.transcript());
+
+    // The resumed prover is bound to the same IO Pattern.
+    let mut resumed = Merlin::<DefaultHash>::import_state(&state, DefaultRng::default()).unwrap();
+    assert!(resumed.add_bytes(&[2u8; 32]).is_err());
+
+    // The verifier accepts the transcript of the resumed prover.
+    let mut arthur = io.to_arthur(merlin.transcript());
+    assert_eq!(arthur.next_bytes::<16>().unwrap(), [1u8; 16]);
+    assert_eq!(arthur.challenge_bytes::<16>().unwrap(), challenge);
+}
+
+#[test]
+fn test_challenge_indices() {
+    let io = IOPattern::<DefaultHash>::new("queries")
+        .add_bytes(8, "commitment")
+        .challenge_indices(1, 1000, "index")
+        .challenge_indices(40, 1 << 20, "queries")
+        .challenge_indices(10, 10, "permutation");
+    assert_eq!(crate::index_bytes(1000, crate::STATISTICAL_SECURITY), 18);
+    assert_eq!(crate::index_bytes(1, crate::STATISTICAL_SECURITY), 16);
+    assert_eq!(crate::index_bytes(1000, 40), 7);
+
+    let mut merlin = io.to_merlin();
+    merlin.add_bytes(&[0x42; 8]).unwrap();
+    let index = merlin.challenge_index(1000).unwrap();
+    let queries = merlin.challenge_indices(40, 1 << 20, false).unwrap();
+    let mut permutation = merlin.challenge_indices(10, 10, true).unwrap();
+    assert!(index < 1000);
+    assert!(queries.iter().all(|&i| i < 1 << 20));
+    permutation.sort();
+    assert_eq!(permutation, (0..10).collect::<Vec<_>>());
+
+    let mut arthur = io.to_arthur(merlin.transcript());
+    arthur.next_bytes::<8>().unwrap();
+    assert_eq!(arthur.challenge_index(1000).unwrap(), index);
+    assert_eq!(
+        arthur.challenge_indices(40, 1 << 20, false).unwrap(),
+        queries
+    );
+
+    // Impossible requests are errors.
+    let mut merlin = io.to_merlin();
+    assert!(merlin.challenge_indices(1, 0, false).is_err());
+    assert!(merlin.challenge_indices(11, 10, true).is_err());
+
+    // The statistical security is the same when sizing the IO Pattern and squeezing.
+    let io = IOPattern::<DefaultHash>::new("queries")
+        .challenge_indices_with_security(5, 1000, 40, "queries");
+    assert_eq!(io.as_bytes(), b"queries\0S35queries");
+    let queries = io
+        .to_merlin()
+        .challenge_indices_with_security(5, 1000, false, 40)
+        .unwrap();
+    assert!(queries.iter().all(|&i| i < 1000));
+    assert!(io.to_merlin().challenge_indices(5, 1000, false).is_err());
 }
@@ -38,6 +38,95 @@ pub trait ByteChallenges {
         let mut output = [0u8; N];
         self.fill_challenge_bytes(&mut output).map(|()| output)
     }
+
+    /// Squeeze `n` uniformly distributed bits, least significant bit of each byte first.
+    fn challenge_bits(&mut self, n: usize) -> Result<Vec<bool>, IOPatternError> {
+        let mut bytes = vec![0u8; n.div_ceil(8)];
+        self.fill_challenge_bytes(&mut bytes)?;
+        Ok((0..n).map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1).collect())
+    }
+
+    /// Squeeze an index uniformly distributed in $[0, n)$, up to a statistical distance of $2^{-\lambda}$,
+    /// where $\lambda$ is [`STATISTICAL_SECURITY`].
+    ///
+    /// The IO Pattern must squeeze [`index_bytes`]`(n, STATISTICAL_SECURITY)` bytes, cf. [`ByteIOPattern::challenge_indices`].
+    fn challenge_index(&mut self, n: usize) -> Result<usize, IOPatternError> {
+        self.challenge_indices(1, n, false)
+            .map(|indices| indices[0])
+    }
+
+    /// Squeeze `count` indices in $[0, n)$, with the default statistical security [`STATISTICAL_SECURITY`],
+    /// cf. [`ByteChallenges::challenge_indices_with_security`].
+    fn challenge_indices(
+        &mut self,
+        count: usize,
+        n: usize,
+        distinct: bool,
+    ) -> Result<Vec<usize>, IOPatternError> {
+        self.challenge_indices_with_security(count, n, distinct, STATISTICAL_SECURITY)
+    }
+
+    /// Squeeze `count` indices in $[0, n)$, each $2^{-\lambda}$-close to uniform, where $\lambda$ is `security`.
+    ///
+    /// If `distinct` is set, the indices are pairwise distinct and `count` must be at most `n`:
+    /// they are the first `count` elements of a random permutation of $[0, n)$ (obtained via Fisher-Yates).
+    /// In both cases, the same number of bytes is squeezed, in a single call to [`ByteChallenges::fill_challenge_bytes`].
+    ///
+    /// The IO Pattern must use the same `security`, cf. [`ByteIOPattern::challenge_indices_with_security`].
+    fn challenge_indices_with_security(
+        &mut self,
+        count: usize,
+        n: usize,
+        distinct: bool,
+        security: usize,
+    ) -> Result<Vec<usize>, IOPatternError> {
+        if n == 0 {
+            return Err("Cannot sample indices in an empty range".into());
+        }
+        if distinct && count > n {
+            return Err(format!("Cannot sample {} distinct indices in [0, {})", count, n).into());
+        }
+        let len = index_bytes(n, security);
+        let mut bytes = vec![0u8; count * len];
+        self.fill_challenge_bytes(&mut bytes)?;
+        let samples = bytes.chunks(len);
+        if !distinct {
+            return Ok(samples.map(|sample| reduce_bytes(sample, n)).collect());
+        }
+        // Partial Fisher-Yates shuffle, keeping track only of the swapped positions.
+        let mut swaps = std::collections::HashMap::new();
+        let indices = samples
+            .enumerate()
+            .map(|(i, sample)| {
+                let j = i + reduce_bytes(sample, n - i);
+                let at_j = *swaps.get(&j).unwrap_or(&j);
+                let at_i = *swaps.get(&i).unwrap_or(&i);
+                swaps.insert(j, at_i);
+                at_j
+            })
+            .collect();
+        Ok(indices)
+    }
+}
+
+/// The default statistical security parameter $\lambda$ used for sampling indices:
+/// each index is $2^{-\lambda}$-close to uniform.
+pub const STATISTICAL_SECURITY: usize = 128;
+
+/// The number of bytes squeezed to sample an index in $[0, n)$ with statistical security $\lambda$ = `security`:
+/// $\lceil (\lceil \log_2 n \rceil + \lambda) / 8 \rceil$.
+pub const fn index_bytes(n: usize, security: usize) -> usize {
+    let bits = (usize::BITS - n.saturating_sub(1).leading_zeros()) as usize;
+    (bits + security).div_ceil(8)
+}
+
+/// Interpret `bytes` as a big-endian integer and reduce it modulo `n`.
+fn reduce_bytes(bytes: &[u8], n: usize) -> usize {
+    let n = n as u128;
+    let reduced = bytes
+        .iter()
+        .fold(0u128, |acc, &b| ((acc << 8) | b as u128) % n);
+    reduced as usize
 }
 
 /// A trait for absorbing and squeezing bytes from a sponge.
@@ -64,6 +153,37 @@ pub trait ByteWriter {
 pub trait ByteIOPattern {
     fn add_bytes(self, count: usize, label: &str) -> Self;
     fn challenge_bytes(self, count: usize, label: &str) -> Self;
+
+    /// Squeeze `count` bits, cf. [`ByteChallenges::challenge_bits`].
+    fn challenge_bits(self, count: usize, label: &str) -> Self
+    where
+        Self: Sized,
+    {
+        self.challenge_bytes(count.div_ceil(8), label)
+    }
+
+    /// Squeeze `count` indices in $[0, n)$, cf. [`ByteChallenges::challenge_indices`].
+    fn challenge_indices(self, count: usize, n: usize, label: &str) -> Self
+    where
+        Self: Sized,
+    {
+        self.challenge_indices_with_security(count, n, STATISTICAL_SECURITY, label)
+    }
+
+    /// Squeeze `count` indices in $[0, n)$ with statistical security `security`,
+    /// cf. [`ByteChallenges::challenge_indices_with_security`].
+    fn challenge_indices_with_security(
+        self,
+        count: usize,
+        n: usize,
+        security: usize,
+        label: &str,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        self.challenge_bytes(count * index_bytes(n, security), label)
+    }
 }
 
 impl<T: UnitTranscript<u8>> BytePublic for T {